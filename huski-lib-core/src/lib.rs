@@ -22,6 +22,19 @@ pub mod ranges {
             | Ranges::Digits => &DIGITS,
             | Ranges::Symbols => &SYMBOLS,
             | Ranges::Table => &TABLE,
+            | Ranges::HexDigits => &HEX_DIGITS,
+            | Ranges::Whitespace => &WHITESPACE,
+            | Ranges::Blank => &BLANK,
+            | Ranges::Alphanumeric => &ALPHANUMERIC,
+            | Ranges::Punctuation => &PUNCTUATION,
+            | Ranges::Graphic => &GRAPHIC,
+            | Ranges::Word => &WORD,
+            | Ranges::Transmission => &TRANSMISSION,
+            | Ranges::FormatEffectors => &FORMAT_EFFECTORS,
+            | Ranges::DeviceControls => &DEVICE_CONTROLS,
+            | Ranges::InformationSeparators => &INFORMATION_SEPARATORS,
+            | Ranges::CodeExtension => &CODE_EXTENSION,
+            | Ranges::Custom(rs) => rs,
         }
     }
 
@@ -44,8 +57,221 @@ pub mod ranges {
         Symbols,
         /// Whole table codes
         Table,
+        /// Hexadecimal digit codes, POSIX `xdigit`
+        HexDigits,
+        /// Whitespace codes, POSIX `space`
+        Whitespace,
+        /// Blank codes, POSIX `blank`
+        Blank,
+        /// Letter and digit codes, POSIX `alnum`
+        Alphanumeric,
+        /// Punctuation codes, POSIX `punct`
+        Punctuation,
+        /// Graphic codes, POSIX `graph`
+        Graphic,
+        /// Word codes, letters, digits and underscore
+        Word,
+        /// Transmission control codes, ISO 1745
+        Transmission,
+        /// Format effector codes
+        FormatEffectors,
+        /// Device control codes
+        DeviceControls,
+        /// Information separator codes
+        InformationSeparators,
+        /// Code extension codes
+        CodeExtension,
+        /// Custom codes, values must fit into range 0-127
+        ///
+        /// ```
+        /// use core::ops::RangeInclusive;
+        /// use huski_lib_core::ranges::{ranges, Ranges};
+        ///
+        /// const SIGNS: Ranges = Ranges::Custom(&[(43..=43), (45..=45)]);
+        ///
+        /// assert_eq!(&[(43..=43), (45..=45)], ranges(SIGNS));
+        /// ```
+        Custom(&'static [RangeInclusive<usize>]),
     }
 
+    /// Metadata acquisition.
+    impl Ranges {
+        /// All variants, except `Ranges::Custom`.
+        pub const ALL: [Ranges; 20] = [
+            Ranges::Printable,
+            Ranges::Control,
+            Ranges::Capital,
+            Ranges::Small,
+            Ranges::Letters,
+            Ranges::Digits,
+            Ranges::Symbols,
+            Ranges::Table,
+            Ranges::HexDigits,
+            Ranges::Whitespace,
+            Ranges::Blank,
+            Ranges::Alphanumeric,
+            Ranges::Punctuation,
+            Ranges::Graphic,
+            Ranges::Word,
+            Ranges::Transmission,
+            Ranges::FormatEffectors,
+            Ranges::DeviceControls,
+            Ranges::InformationSeparators,
+            Ranges::CodeExtension,
+        ];
+
+        /// Functional categories of control codes, ECMA-6 / ISO 646, mutually disjoint.
+        ///
+        /// NUL, BEL, CAN, EM, SUB and DEL fall into none.
+        pub const CATEGORIES: [Ranges; 5] = [
+            Ranges::Transmission,
+            Ranges::FormatEffectors,
+            Ranges::DeviceControls,
+            Ranges::InformationSeparators,
+            Ranges::CodeExtension,
+        ];
+
+        /// Name, suitable for identification.
+        /// ```
+        /// use huski_lib_core::ranges::Ranges;
+        ///
+        /// assert_eq!("hex-digits", Ranges::HexDigits.name());
+        /// ```
+        pub const fn name(&self) -> &'static str {
+            match self {
+                | Ranges::Printable => "printable",
+                | Ranges::Control => "control",
+                | Ranges::Capital => "capital",
+                | Ranges::Small => "small",
+                | Ranges::Letters => "letters",
+                | Ranges::Digits => "digits",
+                | Ranges::Symbols => "symbols",
+                | Ranges::Table => "table",
+                | Ranges::HexDigits => "hex-digits",
+                | Ranges::Whitespace => "whitespace",
+                | Ranges::Blank => "blank",
+                | Ranges::Alphanumeric => "alphanumeric",
+                | Ranges::Punctuation => "punctuation",
+                | Ranges::Graphic => "graphic",
+                | Ranges::Word => "word",
+                | Ranges::Transmission => "transmission",
+                | Ranges::FormatEffectors => "format-effectors",
+                | Ranges::DeviceControls => "device-controls",
+                | Ranges::InformationSeparators => "information-separators",
+                | Ranges::CodeExtension => "code-extension",
+                | Ranges::Custom(_) => "custom",
+            }
+        }
+
+        /// Short flag, as used by `huski` command line utility, without leading dash.
+        ///
+        /// `Ranges::Custom` has no flag, empty string is provided.
+        /// ```
+        /// use huski_lib_core::ranges::Ranges;
+        ///
+        /// assert_eq!("lc", Ranges::Capital.short_flag());
+        /// ```
+        pub const fn short_flag(&self) -> &'static str {
+            match self {
+                | Ranges::Printable => "p",
+                | Ranges::Control => "c",
+                | Ranges::Capital => "lc",
+                | Ranges::Small => "ls",
+                | Ranges::Letters => "l",
+                | Ranges::Digits => "d",
+                | Ranges::Symbols => "s",
+                | Ranges::Table => "t",
+                | Ranges::HexDigits => "hd",
+                | Ranges::Whitespace => "ws",
+                | Ranges::Blank => "b",
+                | Ranges::Alphanumeric => "ad",
+                | Ranges::Punctuation => "pu",
+                | Ranges::Graphic => "g",
+                | Ranges::Word => "w",
+                | Ranges::Transmission => "tc",
+                | Ranges::FormatEffectors => "fe",
+                | Ranges::DeviceControls => "dc",
+                | Ranges::InformationSeparators => "is",
+                | Ranges::CodeExtension => "ce",
+                | Ranges::Custom(_) => "",
+            }
+        }
+
+        /// Human description.
+        /// ```
+        /// use huski_lib_core::ranges::Ranges;
+        ///
+        /// assert_eq!("capital letters", Ranges::Capital.description());
+        /// ```
+        pub const fn description(&self) -> &'static str {
+            match self {
+                | Ranges::Printable => "printable",
+                | Ranges::Control => "control",
+                | Ranges::Capital => "capital letters",
+                | Ranges::Small => "small letters",
+                | Ranges::Letters => "all letters",
+                | Ranges::Digits => "digits",
+                | Ranges::Symbols => "symbols",
+                | Ranges::Table => "whole table",
+                | Ranges::HexDigits => "hexadecimal digits, POSIX xdigit",
+                | Ranges::Whitespace => "whitespace, POSIX space",
+                | Ranges::Blank => "blank, POSIX blank",
+                | Ranges::Alphanumeric => "alphanumeric, POSIX alnum",
+                | Ranges::Punctuation => "punctuation, POSIX punct",
+                | Ranges::Graphic => "graphic, POSIX graph",
+                | Ranges::Word => "word, letters, digits and underscore",
+                | Ranges::Transmission => "transmission controls",
+                | Ranges::FormatEffectors => "format effectors",
+                | Ranges::DeviceControls => "device controls",
+                | Ranges::InformationSeparators => "information separators",
+                | Ranges::CodeExtension => "code extension controls",
+                | Ranges::Custom(_) => "custom",
+            }
+        }
+    }
+
+    impl core::fmt::Display for Ranges {
+        /// Writes `fn name()`.
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str(self.name())
+        }
+    }
+
+    impl core::str::FromStr for Ranges {
+        type Err = ParseRangesError;
+
+        /// Parses either `fn name()` or `fn short_flag()`.
+        ///
+        /// `Ranges::Custom` is never parsed.
+        /// ```
+        /// use huski_lib_core::ranges::Ranges;
+        ///
+        /// assert_eq!(Ok(Ranges::Word), "word".parse());
+        /// assert_eq!(Ok(Ranges::Word), "w".parse());
+        /// ```
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            for r in Ranges::ALL.iter() {
+                if r.name() == s || r.short_flag() == s {
+                    return Ok(r.clone());
+                }
+            }
+
+            Err(ParseRangesError)
+        }
+    }
+
+    /// Input is neither name nor short flag of any `Ranges`.
+    #[derive(Debug, PartialEq, Clone)]
+    pub struct ParseRangesError;
+
+    impl core::fmt::Display for ParseRangesError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str("unknown ranges name or flag")
+        }
+    }
+
+    impl core::error::Error for ParseRangesError {}
+
     use core::ops::RangeInclusive;
 
     /// Printable codes
@@ -64,6 +290,31 @@ pub mod ranges {
     pub static SYMBOLS: [RangeInclusive<usize>; 4] = [(32..=47), (58..=64), (91..=96), (123..=126)];
     /// Whole table codes
     pub static TABLE: [RangeInclusive<usize>; 1] = [(0..=127)];
+    /// Hexadecimal digit codes, POSIX `xdigit`
+    pub static HEX_DIGITS: [RangeInclusive<usize>; 3] = [(48..=57), (65..=70), (97..=102)];
+    /// Whitespace codes, POSIX `space`
+    pub static WHITESPACE: [RangeInclusive<usize>; 2] = [(9..=13), (32..=32)];
+    /// Blank codes, POSIX `blank`
+    pub static BLANK: [RangeInclusive<usize>; 2] = [(9..=9), (32..=32)];
+    /// Letter and digit codes, POSIX `alnum`
+    pub static ALPHANUMERIC: [RangeInclusive<usize>; 3] = [(48..=57), (65..=90), (97..=122)];
+    /// Punctuation codes, POSIX `punct`
+    pub static PUNCTUATION: [RangeInclusive<usize>; 4] =
+        [(33..=47), (58..=64), (91..=96), (123..=126)];
+    /// Graphic codes, POSIX `graph`
+    pub static GRAPHIC: [RangeInclusive<usize>; 1] = [(33..=126)];
+    /// Word codes, letters, digits and underscore
+    pub static WORD: [RangeInclusive<usize>; 4] = [(48..=57), (65..=90), (95..=95), (97..=122)];
+    /// Transmission control codes, ISO 1745
+    pub static TRANSMISSION: [RangeInclusive<usize>; 3] = [(1..=6), (16..=16), (21..=23)];
+    /// Format effector codes
+    pub static FORMAT_EFFECTORS: [RangeInclusive<usize>; 1] = [(8..=13)];
+    /// Device control codes
+    pub static DEVICE_CONTROLS: [RangeInclusive<usize>; 1] = [(17..=20)];
+    /// Information separator codes
+    pub static INFORMATION_SEPARATORS: [RangeInclusive<usize>; 1] = [(28..=31)];
+    /// Code extension codes
+    pub static CODE_EXTENSION: [RangeInclusive<usize>; 2] = [(14..=15), (27..=27)];
 
     #[cfg(test)]
     mod tests_of_units {
@@ -83,6 +334,72 @@ pub mod ranges {
             assert_eq!(&DIGITS, ranges_fn(Ranges::Digits));
             assert_eq!(&SYMBOLS, ranges_fn(Ranges::Symbols));
             assert_eq!(&TABLE, ranges_fn(Ranges::Table));
+            assert_eq!(&HEX_DIGITS, ranges_fn(Ranges::HexDigits));
+            assert_eq!(&WHITESPACE, ranges_fn(Ranges::Whitespace));
+            assert_eq!(&BLANK, ranges_fn(Ranges::Blank));
+            assert_eq!(&ALPHANUMERIC, ranges_fn(Ranges::Alphanumeric));
+            assert_eq!(&PUNCTUATION, ranges_fn(Ranges::Punctuation));
+            assert_eq!(&GRAPHIC, ranges_fn(Ranges::Graphic));
+            assert_eq!(&WORD, ranges_fn(Ranges::Word));
+            assert_eq!(&TRANSMISSION, ranges_fn(Ranges::Transmission));
+            assert_eq!(&FORMAT_EFFECTORS, ranges_fn(Ranges::FormatEffectors));
+            assert_eq!(&DEVICE_CONTROLS, ranges_fn(Ranges::DeviceControls));
+            assert_eq!(
+                &INFORMATION_SEPARATORS,
+                ranges_fn(Ranges::InformationSeparators)
+            );
+            assert_eq!(&CODE_EXTENSION, ranges_fn(Ranges::CodeExtension));
+
+            static CUSTOM: [RangeInclusive<usize>; 2] = [(0..=1), (5..=5)];
+            assert_eq!(&CUSTOM, ranges_fn(Ranges::Custom(&CUSTOM)));
+        }
+
+        mod metadata {
+            extern crate std;
+            use std::{collections::HashSet, string::ToString};
+            use super::{Ranges, ParseRangesError};
+
+            #[test]
+            fn uniqueness() {
+                let len = Ranges::ALL.len();
+
+                let names = Ranges::ALL.iter().map(|x| x.name()).collect::<HashSet<_>>();
+                let flags = Ranges::ALL
+                    .iter()
+                    .map(|x| x.short_flag())
+                    .collect::<HashSet<_>>();
+                let descs = Ranges::ALL
+                    .iter()
+                    .map(|x| x.description())
+                    .collect::<HashSet<_>>();
+
+                assert_eq!(len, names.len());
+                assert_eq!(len, flags.len());
+                assert_eq!(len, descs.len());
+                assert!(names.is_disjoint(&flags));
+            }
+
+            #[test]
+            fn display() {
+                for r in Ranges::ALL.iter() {
+                    assert_eq!(r.name(), r.to_string());
+                }
+
+                assert_eq!("custom", Ranges::Custom(&[]).to_string());
+            }
+
+            #[test]
+            fn from_str() {
+                for r in Ranges::ALL.iter() {
+                    assert_eq!(Ok(r.clone()), r.name().parse());
+                    assert_eq!(Ok(r.clone()), r.short_flag().parse());
+                }
+
+                assert_eq!(Err(ParseRangesError), "custom".parse::<Ranges>());
+                assert_eq!(Err(ParseRangesError), "".parse::<Ranges>());
+                assert_eq!(Err(ParseRangesError), "-p".parse::<Ranges>());
+                assert_eq!(Err(ParseRangesError), "Printable".parse::<Ranges>());
+            }
         }
 
         #[test]
@@ -191,6 +508,204 @@ pub mod ranges {
 
             assert_eq!(proof, symbols);
         }
+
+        #[test]
+        fn hex_digits() {
+            let start_d = '0' as usize; // 48
+            let end_d = '9' as usize; // 57
+
+            let start_c = 'A' as usize; // 65
+            let end_c = 'F' as usize; // 70
+
+            let start_s = 'a' as usize; // 97
+            let end_s = 'f' as usize; // 102
+
+            assert_eq!(3, HEX_DIGITS.len());
+
+            let hex_digits = ccr2!(&HEX_DIGITS);
+            let proof = ccr1!(start_d..=end_d, start_c..=end_c, start_s..=end_s);
+
+            assert_eq!(proof, hex_digits);
+        }
+
+        #[test]
+        fn whitespace() {
+            let ht = '\t' as usize; // 9
+            let cr = '\r' as usize; // 13
+            let space = ' ' as usize; // 32
+
+            assert_eq!(2, WHITESPACE.len());
+
+            let whitespace = ccr2!(&WHITESPACE);
+            let proof = ccr1!(ht..=cr, space..=space);
+
+            assert_eq!(proof, whitespace);
+        }
+
+        #[test]
+        fn blank() {
+            let ht = '\t' as usize; // 9
+            let space = ' ' as usize; // 32
+
+            assert_eq!(2, BLANK.len());
+
+            let blank = ccr2!(&BLANK);
+            let proof = ccr1!(ht..=ht, space..=space);
+
+            assert_eq!(proof, blank);
+        }
+
+        #[test]
+        fn alphanumeric() {
+            let start_d = '0' as usize; // 48
+            let end_d = '9' as usize; // 57
+
+            let start_c = 'A' as usize; // 65
+            let end_c = 'Z' as usize; // 90
+
+            let start_s = 'a' as usize; // 97
+            let end_s = 'z' as usize; // 122
+
+            assert_eq!(3, ALPHANUMERIC.len());
+
+            let alphanumeric = ccr2!(&ALPHANUMERIC);
+            let proof = ccr1!(start_d..=end_d, start_c..=end_c, start_s..=end_s);
+
+            assert_eq!(proof, alphanumeric);
+        }
+
+        #[test]
+        fn punctuation() {
+            let start_1 = '!' as usize; // 33
+            let end_1 = '/' as usize; // 47
+
+            let start_2 = ':' as usize; // 58
+            let end_2 = '@' as usize; // 64
+
+            let start_3 = '[' as usize; // 91
+            let end_3 = '`' as usize; // 96
+
+            let start_4 = '{' as usize; // 123
+            let end_4 = '~' as usize; // 126
+
+            assert_eq!(4, PUNCTUATION.len());
+
+            let punctuation = ccr2!(&PUNCTUATION);
+            let proof = ccr1!(
+                start_1..=end_1,
+                start_2..=end_2,
+                start_3..=end_3,
+                start_4..=end_4
+            );
+
+            assert_eq!(proof, punctuation);
+        }
+
+        #[test]
+        fn graphic() {
+            let start = '!' as usize; // 33
+            let end = '~' as usize; // 126
+
+            assert_eq!(1, GRAPHIC.len());
+
+            let graphic = ccr2!(&GRAPHIC);
+            let proof = ccr1!(start..=end);
+
+            assert_eq!(proof, graphic);
+        }
+
+        #[test]
+        fn word() {
+            let start_d = '0' as usize; // 48
+            let end_d = '9' as usize; // 57
+
+            let start_c = 'A' as usize; // 65
+            let end_c = 'Z' as usize; // 90
+
+            let underscore = '_' as usize; // 95
+
+            let start_s = 'a' as usize; // 97
+            let end_s = 'z' as usize; // 122
+
+            assert_eq!(4, WORD.len());
+
+            let word = ccr2!(&WORD);
+            let proof = ccr1!(
+                start_d..=end_d,
+                start_c..=end_c,
+                underscore..=underscore,
+                start_s..=end_s
+            );
+
+            assert_eq!(proof, word);
+        }
+
+        #[test]
+        fn transmission() {
+            let test = ccr2!(&TRANSMISSION);
+            let proof = ccr1!(1..=6, 16..=16, 21..=23);
+
+            assert_eq!(3, TRANSMISSION.len());
+            assert_eq!(proof, test);
+        }
+
+        #[test]
+        fn format_effectors() {
+            let bs = 0x8; // 8
+            let cr = '\r' as usize; // 13
+
+            let test = ccr2!(&FORMAT_EFFECTORS);
+            let proof = ccr1!(bs..=cr);
+
+            assert_eq!(1, FORMAT_EFFECTORS.len());
+            assert_eq!(proof, test);
+        }
+
+        #[test]
+        fn device_controls() {
+            let test = ccr2!(&DEVICE_CONTROLS);
+            let proof = ccr1!(17..=20);
+
+            assert_eq!(1, DEVICE_CONTROLS.len());
+            assert_eq!(proof, test);
+        }
+
+        #[test]
+        fn information_separators() {
+            let test = ccr2!(&INFORMATION_SEPARATORS);
+            let proof = ccr1!(28..=31);
+
+            assert_eq!(1, INFORMATION_SEPARATORS.len());
+            assert_eq!(proof, test);
+        }
+
+        #[test]
+        fn code_extension() {
+            let test = ccr2!(&CODE_EXTENSION);
+            let proof = ccr1!(14..=15, 27..=27);
+
+            assert_eq!(2, CODE_EXTENSION.len());
+            assert_eq!(proof, test);
+        }
+
+        #[test]
+        fn categories() {
+            let mut proof = [0; 128];
+            for r in Ranges::CATEGORIES.iter() {
+                for c in ccr2!(ranges_fn(r.clone())) {
+                    proof[c] += 1;
+                }
+            }
+
+            for (ix, count) in proof.iter().enumerate() {
+                let expected = match ix {
+                    | 0 | 7 | 24..=26 | 32..=127 => 0,
+                    | _ => 1,
+                };
+
+                assert_eq!(expected, *count, "{}", ix);
+            }
+        }
     }
 }
 
@@ -328,4 +843,3477 @@ pub mod table {
         ("~", "Tilde"),
         ("DEL", "Delete"),
     ];
+
+    /// ASCII table aliases, alternate names of codes used in the wild
+    pub static ALIASES: [&[&str]; 128] = [
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &["TAB"],
+        &["NL"],
+        &[],
+        &["NP"],
+        &[],
+        &[],
+        &[],
+        &[],
+        &["XON"],
+        &[],
+        &["XOFF"],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &["EOF"],
+        &["ALT"],
+        &[],
+        &[],
+        &[],
+        &[],
+        &["SP"],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &["RUBOUT"],
+    ];
+
+    /// ASCII table long descriptions, historic purpose and modern usage of code
+    pub static ABOUT: [Option<&str>; 128] = [
+        Some(
+            "Null. Originally paper tape with no holes punched, used as time fill. C and many APIs use it as string terminator, thus it rarely survives inside text.",
+        ),
+        Some(
+            "Start of heading. Opened message header in early transmission protocols; modern use is limited to some binary framing and terminal multiplexers.",
+        ),
+        Some(
+            "Start of text. Ended header and started message body. Still used as frame start by serial protocols, card readers and barcode scanners.",
+        ),
+        Some(
+            "End of text. Ended message body. Terminals send it for Ctrl+C, which Unix tty turns into SIGINT interrupt.",
+        ),
+        Some(
+            "End of transmission. Ended whole transmission. Unix tty treats Ctrl+D as end of input, closing interactive shells.",
+        ),
+        Some(
+            "Enquiry. Requested response from remote station, e.g. its identification. Some terminals still answer it with configurable answerback message.",
+        ),
+        Some(
+            "Acknowledge. Positive reply of receiver. Used by serial protocols, e.g. XMODEM, and by many device protocols over RS-232.",
+        ),
+        Some(
+            "Bell. Rang bell of teletype to draw attention. Terminals beep or flash screen; also ends OSC sequences in xterm-like terminals.",
+        ),
+        Some(
+            "Backspace. Moves cursor one position back, historically to overstrike characters. Backspace key sends it or DEL depending on terminal setup.",
+        ),
+        Some(
+            "Horizontal tabulation. Moves to next tab stop, usually every 8 columns. Common indentation character and field separator of TSV files.",
+        ),
+        Some(
+            "Line feed. Advances paper by one line. Unix line ending alone, and second half of CR LF line ending used by Windows and internet protocols.",
+        ),
+        Some(
+            "Vertical tabulation. Advanced paper to next vertical tab stop. Rarely used today, C still treats it as whitespace.",
+        ),
+        Some(
+            "Form feed. Ejected page on printers. Clears screen in many terminals and separates pages in source code and plain text documents.",
+        ),
+        Some(
+            "Carriage return. Returns carriage to line start. Classic Mac OS line ending alone, and first half of CR LF. Enter key sends it to terminals.",
+        ),
+        Some(
+            "Shift out. Switched to alternate character set, e.g. line drawing in VT100. Stray SO can garble terminal, reset or SI fixes it.",
+        ),
+        Some(
+            "Shift in. Switched back to standard character set after SO.",
+        ),
+        Some(
+            "Data link escape. Changed meaning of following codes, allowing control codes inside transmitted data. Used by some binary-synchronous and modem protocols.",
+        ),
+        Some(
+            "Device control 1. Known as XON, resumes transmission in software flow control. Terminals send it for Ctrl+Q.",
+        ),
+        Some(
+            "Device control 2. Historically turned on tape punch or auxiliary device. No common modern use.",
+        ),
+        Some(
+            "Device control 3. Known as XOFF, pauses transmission in software flow control. Terminals send it for Ctrl+S, freezing output until XON.",
+        ),
+        Some(
+            "Device control 4. Historically turned off tape punch or auxiliary device. No common modern use.",
+        ),
+        Some(
+            "Negative acknowledge. Negative reply of receiver, requesting retransmission. Used by XMODEM and similar serial protocols.",
+        ),
+        Some(
+            "Synchronous idle. Kept synchronous link in sync when no data was sent. Used by binary-synchronous protocols.",
+        ),
+        Some(
+            "End of transmission block. Ended block of data in block-oriented transmission. Used by binary-synchronous protocols.",
+        ),
+        Some(
+            "Cancel. Indicated preceding data are in error. Cancels XMODEM transfer and aborts escape sequence in terminals.",
+        ),
+        Some(
+            "End of medium. Marked physical end of tape or card. No common modern use.",
+        ),
+        Some(
+            "Substitute. Replaced invalid or garbled character. DOS and CP/M used Ctrl+Z as end-of-file marker of text files, Windows console still does.",
+        ),
+        Some(
+            "Escape. Changed meaning of following characters. Starts ANSI escape sequences for terminal color and cursor control; Alt key combinations are sent with ESC prefix.",
+        ),
+        Some(
+            "File separator. Delimited highest level of data structure. Unix tty sends SIGQUIT for Ctrl+\\. Rarely used as data delimiter.",
+        ),
+        Some(
+            "Group separator. Delimited group of records. Used by GS1 barcodes to separate variable-length fields.",
+        ),
+        Some(
+            "Record separator. Delimited records. Used by JSON text sequences (RFC 7464) to prefix each JSON text.",
+        ),
+        Some(
+            "Unit separator. Delimited units, i.e. fields, of record. Occasionally used as field delimiter where tab or comma may occur in data.",
+        ),
+        Some(
+            "Space. Separates words. Counted both as printable character and as whitespace.",
+        ),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(
+            "Delete. Punched all holes of paper tape, erasing character. Backspace key sends it in many terminal configurations.",
+        ),
+    ];
+}
+
+/// Module contains ASCII code information in form of `Code`s.
+pub mod code {
+
+    use core::ops::RangeInclusive;
+    use crate::ranges::{ranges, Ranges};
+    use crate::table::{ABOUT, ALIASES, TABLE};
+    use crate::class::is;
+    use crate::notation;
+    use crate::naming::Scheme;
+
+    /// Provides information about ASCII code
+    #[derive(Debug, PartialEq, Clone)]
+    pub struct Code {
+        code: u8,
+        human: &'static str,
+        desc: &'static str,
+    }
+
+    /// Value acquisition.
+    impl Code {
+        /// Decimal code value.
+        pub const fn code(&self) -> u8 {
+            self.code
+        }
+
+        /// Human representation.
+        pub const fn human(&self) -> &'static str {
+            self.human
+        }
+
+        /// Code description.
+        pub const fn desc(&self) -> &'static str {
+            self.desc
+        }
+
+        /// Alternate names, i.e. `XON` for DC1.
+        pub const fn aliases(&self) -> &'static [&'static str] {
+            ALIASES[self.code as usize]
+        }
+
+        /// Long description, i.e. historic purpose and modern usage. Control codes and space only.
+        pub const fn about(&self) -> Option<&'static str> {
+            ABOUT[self.code as usize]
+        }
+
+        /// Functional category of control code, see `Ranges::CATEGORIES`.
+        /// ```
+        /// use huski_lib_core::{code::CODES, ranges::Ranges};
+        ///
+        /// assert_eq!(Some(Ranges::DeviceControls), CODES[0x11].category());
+        /// assert_eq!(None, CODES[0x07].category());
+        /// ```
+        pub fn category(&self) -> Option<Ranges> {
+            Ranges::CATEGORIES
+                .iter()
+                .find(|x| is((*x).clone(), self.code))
+                .cloned()
+        }
+    }
+
+    /// Notation acquisition. See `notation` module.
+    impl Code {
+        /// Caret notation, i.e. `^[` for ESC. Control codes only.
+        pub const fn caret(&self) -> Option<&'static str> {
+            notation::CARET[self.code as usize]
+        }
+
+        /// C escape sequence, i.e. `\x1b` for ESC or `\n` for LF.
+        pub const fn c_escape(&self) -> &'static str {
+            notation::C_ESCAPE[self.code as usize]
+        }
+
+        /// Rust escape sequence, i.e. `\x1b` for ESC or `\n` for LF.
+        pub const fn rust_escape(&self) -> &'static str {
+            notation::RUST_ESCAPE[self.code as usize]
+        }
+
+        /// Unicode code point notation, i.e. `U+001B` for ESC.
+        pub const fn unicode(&self) -> &'static str {
+            notation::UNICODE[self.code as usize]
+        }
+
+        /// HTML numeric character reference, i.e. `&#27;` for ESC.
+        pub const fn html(&self) -> &'static str {
+            notation::HTML[self.code as usize]
+        }
+
+        /// HTML named character reference, i.e. `&amp;` for `&`, where defined.
+        pub const fn html_entity(&self) -> Option<&'static str> {
+            notation::HTML_ENTITY[self.code as usize]
+        }
+
+        /// URL percent-encoding, i.e. `%1B` for ESC. Unreserved characters
+        /// are encoded too, i.e. `%41` for `A`, see `notation::PERCENT`.
+        pub const fn percent(&self) -> &'static str {
+            notation::PERCENT[self.code as usize]
+        }
+
+        /// Unicode Control Pictures glyph, i.e. `␛` for ESC. Control codes and space only.
+        pub const fn picture(&self) -> Option<&'static str> {
+            notation::PICTURE[self.code as usize]
+        }
+    }
+
+    /// Name acquisition. See `naming` module.
+    impl Code {
+        /// Name of code in scheme `s`, `None` where `s` does not name it.
+        /// ```
+        /// use huski_lib_core::{code::CODES, naming::Scheme};
+        ///
+        /// let esc = &CODES[27];
+        /// assert_eq!(Some("ESCAPE"), esc.name(Scheme::Iso6429));
+        /// assert_eq!(Some("controlESC"), esc.name(Scheme::Adobe));
+        /// ```
+        pub const fn name(&self, s: Scheme) -> Option<&'static str> {
+            s.names()[self.code as usize]
+        }
+    }
+
+    impl TryFrom<u8> for Code {
+        type Error = OutOfTable;
+
+        /// Provides `Code` for `value`.
+        /// ```
+        /// use huski_lib_core::code::Code;
+        ///
+        /// assert_eq!("ESC", Code::try_from(27u8).unwrap().human());
+        /// assert_eq!(128, Code::try_from(128u8).unwrap_err().value());
+        /// ```
+        fn try_from(value: u8) -> Result<Self, Self::Error> {
+            match CODES.get(value as usize) {
+                | Some(c) => Ok(c.clone()),
+                | None => Err(OutOfTable { value: value as u32 }),
+            }
+        }
+    }
+
+    impl TryFrom<char> for Code {
+        type Error = OutOfTable;
+
+        /// Provides `Code` for `value`.
+        /// ```
+        /// use huski_lib_core::code::Code;
+        ///
+        /// assert_eq!("Tilde", Code::try_from('~').unwrap().desc());
+        /// assert_eq!('€' as u32, Code::try_from('€').unwrap_err().value());
+        /// ```
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            match CODES.get(value as usize) {
+                | Some(c) => Ok(c.clone()),
+                | None => Err(OutOfTable { value: value as u32 }),
+            }
+        }
+    }
+
+    /// Value does not fit into ASCII table range 0-127.
+    #[derive(Debug, PartialEq, Clone)]
+    pub struct OutOfTable {
+        value: u32,
+    }
+
+    impl OutOfTable {
+        /// Offending value.
+        pub const fn value(&self) -> u32 {
+            self.value
+        }
+    }
+
+    impl core::fmt::Display for OutOfTable {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "value {} is out of table range 0-127", self.value)
+        }
+    }
+
+    impl core::error::Error for OutOfTable {}
+
+    /// ASCII table in form of `Code`s, indexed by code value.
+    pub static CODES: [Code; 128] = codes();
+
+    const fn codes() -> [Code; 128] {
+        const NUL: Code = Code {
+            code: 0,
+            human: "",
+            desc: "",
+        };
+
+        let mut codes = [NUL; 128];
+
+        let mut ix = 0;
+        while ix < 128 {
+            let info = TABLE[ix];
+            codes[ix] = Code {
+                code: ix as u8,
+                human: info.0,
+                desc: info.1,
+            };
+
+            ix += 1;
+        }
+
+        codes
+    }
+
+    /// Provides `&[Code]` view into `CODES` for `r`.
+    ///
+    /// Range must fit into range 0-127, otherwise function will panic.
+    /// ```
+    /// use huski_lib_core::{code::view, ranges::DIGITS};
+    ///
+    /// let v = view(&DIGITS[0]);
+    /// assert_eq!(10, v.len());
+    /// assert_eq!('0', v[0].code() as char);
+    /// ```
+    pub const fn view(r: &RangeInclusive<usize>) -> &'static [Code] {
+        let (head, _) = CODES.split_at(*r.end() + 1);
+        let (_, view) = head.split_at(*r.start());
+        view
+    }
+
+    /// Provides `&[Code]` views for demand of `r`, one for each of its ranges.
+    /// ```
+    /// use huski_lib_core::{code::views, ranges::Ranges};
+    ///
+    /// let vs = views(Ranges::Control).collect::<Vec<_>>();
+    /// assert_eq!(2, vs.len());
+    /// assert_eq!("NUL", vs[0][0].human());
+    /// assert_eq!("DEL", vs[1][0].human());
+    /// ```
+    pub fn views(
+        r: Ranges,
+    ) -> impl ExactSizeIterator<Item = &'static [Code]> + DoubleEndedIterator {
+        ranges(r).iter().map(view)
+    }
+
+    #[cfg(test)]
+    mod tests_of_units {
+
+        extern crate std;
+        use std::vec::Vec;
+        use super::{Code, CODES, view, views};
+        use crate::ranges::{Ranges, CONTROL, TABLE as TABLE_RANGES};
+        use crate::table::TABLE;
+
+        #[test]
+        fn code() {
+            let code = 99;
+            let human = "human";
+            let desc = "desc";
+
+            let test = Code { code, human, desc };
+
+            assert_eq!(code, test.code());
+            assert_eq!(human, test.human());
+            assert_eq!(desc, test.desc());
+        }
+
+        #[test]
+        fn codes() {
+            for (ix, c) in CODES.iter().enumerate() {
+                let info = TABLE[ix];
+
+                assert_eq!(ix as u8, c.code);
+                assert_eq!(info.0, c.human);
+                assert_eq!(info.1, c.desc);
+            }
+        }
+
+        #[test]
+        fn aliases() {
+            assert_eq!(["XON"], CODES[17].aliases());
+            assert_eq!(["XOFF"], CODES[19].aliases());
+            assert_eq!(["RUBOUT"], CODES[127].aliases());
+            assert!(CODES[65].aliases().is_empty());
+        }
+
+        #[test]
+        fn about() {
+            for c in CODES.iter() {
+                let control = c.code() < 33 || c.code() == 127;
+                assert_eq!(control, c.about().is_some());
+            }
+
+            assert!(CODES[27].about().unwrap().contains("ANSI"));
+        }
+
+        #[test]
+        fn category() {
+            assert_eq!(Some(Ranges::Transmission), CODES[0x01].category());
+            assert_eq!(Some(Ranges::FormatEffectors), CODES[0x0a].category());
+            assert_eq!(Some(Ranges::DeviceControls), CODES[0x14].category());
+            assert_eq!(Some(Ranges::InformationSeparators), CODES[0x1c].category());
+            assert_eq!(Some(Ranges::CodeExtension), CODES[0x0e].category());
+            assert_eq!(None, CODES[0x00].category());
+            assert_eq!(None, CODES[0x7f].category());
+            assert_eq!(None, CODES[b'a' as usize].category());
+        }
+
+        #[test]
+        fn notations() {
+            let esc = &CODES[27];
+            assert_eq!(Some("^["), esc.caret());
+            assert_eq!("\\x1b", esc.c_escape());
+            assert_eq!("\\x1b", esc.rust_escape());
+            assert_eq!("U+001B", esc.unicode());
+            assert_eq!("&#27;", esc.html());
+            assert_eq!(None, esc.html_entity());
+            assert_eq!("%1B", esc.percent());
+            assert_eq!(Some("\u{241b}"), esc.picture());
+
+            let lf = &CODES[10];
+            assert_eq!("\\n", lf.c_escape());
+            assert_eq!(Some("&NewLine;"), lf.html_entity());
+
+            let amp = &CODES[38];
+            assert_eq!(None, amp.caret());
+            assert_eq!("&", amp.c_escape());
+            assert_eq!(Some("&amp;"), amp.html_entity());
+            assert_eq!(None, amp.picture());
+        }
+
+        #[test]
+        fn name() {
+            use crate::naming::Scheme;
+
+            let a = &CODES[b'a' as usize];
+            assert_eq!(Some("LATIN SMALL LETTER A"), a.name(Scheme::Unicode));
+            assert_eq!(None, a.name(Scheme::Iso6429));
+            assert_eq!(Some("a"), a.name(Scheme::Rfc1345));
+            assert_eq!(Some("a"), a.name(Scheme::Adobe));
+            assert_eq!(Some("a"), a.name(Scheme::X11));
+
+            let lf = &CODES[10];
+            assert_eq!(None, lf.name(Scheme::Unicode));
+            assert_eq!(Some("LINE FEED (LF)"), lf.name(Scheme::Iso6429));
+            assert_eq!(Some("LF"), lf.name(Scheme::Rfc1345));
+            assert_eq!(Some("controlLF"), lf.name(Scheme::Adobe));
+            assert_eq!(Some("Linefeed"), lf.name(Scheme::X11));
+        }
+
+        mod try_from {
+            extern crate std;
+            use std::string::ToString;
+            use super::{Code, CODES};
+
+            #[test]
+            fn u8() {
+                for i in 0..=127u8 {
+                    assert_eq!(Ok(CODES[i as usize].clone()), Code::try_from(i));
+                }
+
+                for i in 128..=255u8 {
+                    let err = Code::try_from(i).unwrap_err();
+                    assert_eq!(i as u32, err.value());
+                }
+            }
+
+            #[test]
+            fn char() {
+                assert_eq!(Ok(CODES[0].clone()), Code::try_from('\0'));
+                assert_eq!(Ok(CODES[127].clone()), Code::try_from('\x7f'));
+
+                let err = Code::try_from('\u{80}').unwrap_err();
+                assert_eq!(128, err.value());
+            }
+
+            #[test]
+            fn display() {
+                let err = Code::try_from(200u8).unwrap_err();
+                assert_eq!("value 200 is out of table range 0-127", err.to_string());
+            }
+        }
+
+        mod view {
+            use super::{view, CODES};
+
+            #[test]
+            fn basic_test() {
+                let test = view(&(3..=5));
+                assert_eq!(&CODES[3..=5], test);
+            }
+
+            #[test]
+            fn single() {
+                let test = view(&(127..=127));
+                assert_eq!(&CODES[127..=127], test);
+            }
+
+            #[test]
+            #[should_panic]
+            fn index_128() {
+                _ = view(&(127..=128));
+            }
+        }
+
+        #[test]
+        fn views_test() {
+            let test = views(Ranges::Control);
+            assert_eq!(2, test.len());
+
+            for (v, r) in test.zip(CONTROL.iter()) {
+                assert_eq!(&CODES[r.clone()], v);
+            }
+
+            let test = views(Ranges::Table).collect::<Vec<_>>();
+            assert_eq!(&[&CODES[TABLE_RANGES[0].clone()]], test.as_slice());
+        }
+    }
+}
+
+/// Module contains lazy iteration over ASCII codes.
+///
+/// Counterparts of `huski-lib` acquisition functions, without allocation.
+pub mod iter {
+
+    use core::iter::FusedIterator;
+    use core::ops::RangeInclusive;
+    use core::slice::Iter;
+    use crate::code::{Code, CODES};
+    use crate::ranges::{ranges, Ranges};
+
+    /// Provides `Codes` iterator for ranges specified.
+    ///
+    /// Input values must fit into range 0-127, otherwise
+    /// iteration will panic.
+    ///
+    /// Duplicities and input order are preserved.
+    /// ```
+    /// use huski_lib_core::{iter::codes, ranges::LETTERS};
+    ///
+    /// let mut cs = codes(&LETTERS);
+    /// assert_eq!(52, cs.len());
+    /// assert_eq!('A', cs.next().unwrap().code() as char);
+    /// assert_eq!('z', cs.next_back().unwrap().code() as char);
+    /// ```
+    pub fn codes(rs: &[RangeInclusive<usize>]) -> Codes<'_> {
+        Codes::new(rs)
+    }
+
+    /// Provides `Codes` iterator for demand of `r`.
+    /// ```
+    /// use huski_lib_core::{iter::iter, ranges::Ranges};
+    ///
+    /// let mut cs = iter(Ranges::Digits);
+    /// assert_eq!(10, cs.len());
+    /// assert_eq!('0', cs.next().unwrap().code() as char);
+    /// ```
+    pub fn iter(r: Ranges) -> Codes<'static> {
+        Codes::new(ranges(r))
+    }
+
+    /// Similar to `fn codes()` but it accepts `&[Ranges]` as input
+    /// and iterates merged open result.
+    /// ```
+    /// use huski_lib_core::{iter::acquire, ranges::Ranges};
+    ///
+    /// let mut cs = acquire(&[Ranges::Capital, Ranges::Small]);
+    /// assert_eq!(52, cs.len());
+    /// assert_eq!('A', cs.next().unwrap().code() as char);
+    /// assert_eq!('z', cs.next_back().unwrap().code() as char);
+    /// ```
+    pub fn acquire(rs: &[Ranges]) -> Acquire<'_> {
+        let mut len = 0;
+        for r in rs {
+            len += Codes::new(ranges(r.clone())).len;
+        }
+
+        Acquire {
+            rs: rs.iter(),
+            front: None,
+            back: None,
+            len,
+        }
+    }
+
+    /// Similar to `fn codes()` but it accepts `&[Ranges]` as input
+    /// and iterates open result in apart.
+    /// ```
+    /// use huski_lib_core::{iter::acquire_apart, ranges::Ranges};
+    ///
+    /// let mut apart = acquire_apart(&[Ranges::Capital, Ranges::Small]);
+    /// assert_eq!(2, apart.len());
+    /// assert_eq!('A', apart.next().unwrap().next().unwrap().code() as char);
+    /// assert_eq!('z', apart.next().unwrap().next_back().unwrap().code() as char);
+    /// ```
+    pub fn acquire_apart(rs: &[Ranges]) -> AcquireApart<'_> {
+        AcquireApart { rs: rs.iter() }
+    }
+
+    /// Iterator over `Code`s of `RangeInclusive<usize>` listing.
+    #[derive(Clone, Debug)]
+    pub struct Codes<'a> {
+        rs: &'a [RangeInclusive<usize>],
+        // range index and next value from front
+        front: usize,
+        front_at: usize,
+        // range index and exclusive end of next value from back
+        back: usize,
+        back_end: usize,
+        len: usize,
+    }
+
+    impl<'a> Codes<'a> {
+        fn new(rs: &'a [RangeInclusive<usize>]) -> Self {
+            let mut len = 0;
+            for r in rs {
+                len += (*r.end() + 1).saturating_sub(*r.start());
+            }
+
+            let (front_at, back, back_end) = match (rs.first(), rs.last()) {
+                | (Some(f), Some(l)) => (*f.start(), rs.len() - 1, *l.end() + 1),
+                | _ => (0, 0, 0),
+            };
+
+            Codes {
+                rs,
+                front: 0,
+                front_at,
+                back,
+                back_end,
+                len,
+            }
+        }
+    }
+
+    impl Iterator for Codes<'_> {
+        type Item = &'static Code;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.len == 0 {
+                return None;
+            }
+
+            while self.front_at > *self.rs[self.front].end() {
+                self.front += 1;
+                self.front_at = *self.rs[self.front].start();
+            }
+
+            let code = &CODES[self.front_at];
+            self.front_at += 1;
+            self.len -= 1;
+
+            Some(code)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.len, Some(self.len))
+        }
+    }
+
+    impl DoubleEndedIterator for Codes<'_> {
+        fn next_back(&mut self) -> Option<Self::Item> {
+            if self.len == 0 {
+                return None;
+            }
+
+            while self.back_end <= *self.rs[self.back].start() {
+                self.back -= 1;
+                self.back_end = *self.rs[self.back].end() + 1;
+            }
+
+            self.back_end -= 1;
+            self.len -= 1;
+
+            Some(&CODES[self.back_end])
+        }
+    }
+
+    impl ExactSizeIterator for Codes<'_> {}
+
+    impl FusedIterator for Codes<'_> {}
+
+    /// Iterator over `Code`s of `Ranges` listing, merged.
+    #[derive(Clone, Debug)]
+    pub struct Acquire<'a> {
+        rs: Iter<'a, Ranges>,
+        front: Option<Codes<'static>>,
+        back: Option<Codes<'static>>,
+        len: usize,
+    }
+
+    impl Iterator for Acquire<'_> {
+        type Item = &'static Code;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            loop {
+                if let Some(f) = self.front.as_mut() {
+                    if let Some(c) = f.next() {
+                        self.len -= 1;
+                        return Some(c);
+                    }
+                }
+
+                match self.rs.next() {
+                    | Some(r) => self.front = Some(iter(r.clone())),
+                    | None => {
+                        let c = self.back.as_mut()?.next()?;
+                        self.len -= 1;
+                        return Some(c);
+                    },
+                }
+            }
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.len, Some(self.len))
+        }
+    }
+
+    impl DoubleEndedIterator for Acquire<'_> {
+        fn next_back(&mut self) -> Option<Self::Item> {
+            loop {
+                if let Some(b) = self.back.as_mut() {
+                    if let Some(c) = b.next_back() {
+                        self.len -= 1;
+                        return Some(c);
+                    }
+                }
+
+                match self.rs.next_back() {
+                    | Some(r) => self.back = Some(iter(r.clone())),
+                    | None => {
+                        let c = self.front.as_mut()?.next_back()?;
+                        self.len -= 1;
+                        return Some(c);
+                    },
+                }
+            }
+        }
+    }
+
+    impl ExactSizeIterator for Acquire<'_> {}
+
+    impl FusedIterator for Acquire<'_> {}
+
+    /// Iterator over `Codes` of `Ranges` listing, in apart.
+    #[derive(Clone, Debug)]
+    pub struct AcquireApart<'a> {
+        rs: Iter<'a, Ranges>,
+    }
+
+    impl Iterator for AcquireApart<'_> {
+        type Item = Codes<'static>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.rs.next().map(|r| iter(r.clone()))
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.rs.size_hint()
+        }
+    }
+
+    impl DoubleEndedIterator for AcquireApart<'_> {
+        fn next_back(&mut self) -> Option<Self::Item> {
+            self.rs.next_back().map(|r| iter(r.clone()))
+        }
+    }
+
+    impl ExactSizeIterator for AcquireApart<'_> {}
+
+    impl FusedIterator for AcquireApart<'_> {}
+
+    #[cfg(test)]
+    mod tests_of_units {
+
+        extern crate std;
+        use std::vec::Vec;
+        use super::*;
+        use crate::ranges::{ranges as ranges_fn, LETTERS, SYMBOLS};
+        use huski_auxies::{ccr1, ccr2, len};
+
+        fn proof(rs: &[RangeInclusive<usize>]) -> Vec<&'static Code> {
+            ccr2!(rs).into_iter().map(|i| &CODES[i]).collect()
+        }
+
+        mod codes {
+            use super::*;
+
+            #[test]
+            fn basic_test() {
+                let rs = [(0..=2), (125..=127)];
+                let test = codes(&rs);
+
+                assert_eq!(6, test.len());
+                assert_eq!(proof(&rs), test.collect::<Vec<_>>());
+            }
+
+            #[test]
+            fn reversed() {
+                let rs = [(0..=2), (125..=127)];
+                let test = codes(&rs).rev().collect::<Vec<_>>();
+
+                let mut proof = proof(&rs);
+                proof.reverse();
+                assert_eq!(proof, test);
+            }
+
+            #[test]
+            fn both_ends() {
+                let rs = [(0..=1), (3..=3), (5..=6)];
+                let mut test = codes(&rs);
+
+                assert_eq!(0, test.next().unwrap().code());
+                assert_eq!(6, test.next_back().unwrap().code());
+                assert_eq!(1, test.next().unwrap().code());
+                assert_eq!(5, test.next_back().unwrap().code());
+                assert_eq!(1, test.len());
+                assert_eq!(3, test.next_back().unwrap().code());
+                assert_eq!(0, test.len());
+                assert_eq!(None, test.next());
+                assert_eq!(None, test.next_back());
+            }
+
+            #[test]
+            fn preservation() {
+                let rs = [127..=127, 127..=127, 0..=0];
+                let test = codes(&rs);
+
+                assert_eq!(proof(&rs), test.collect::<Vec<_>>());
+            }
+
+            #[test]
+            #[allow(clippy::reversed_empty_ranges)]
+            fn empty_ranges() {
+                let rs = [(5..=3), (0..=0), (9..=8)];
+                let test = codes(&rs);
+                assert_eq!(1, test.len());
+                assert_eq!([&CODES[0]].as_slice(), test.collect::<Vec<_>>());
+
+                let test = codes(&rs).rev();
+                assert_eq!([&CODES[0]].as_slice(), test.collect::<Vec<_>>());
+
+                let mut test = codes(&[]);
+                assert_eq!(0, test.len());
+                assert_eq!(None, test.next());
+                assert_eq!(None, test.next_back());
+            }
+
+            #[test]
+            #[should_panic(expected = "index out of bounds: the len is 128 but the index is 128")]
+            fn index_128() {
+                _ = codes(&[128..=128]).next();
+            }
+        }
+
+        #[test]
+        fn iter_test() {
+            let test = iter(Ranges::Symbols).collect::<Vec<_>>();
+            assert_eq!(proof(&SYMBOLS), test);
+        }
+
+        #[test]
+        fn custom() {
+            const R: Ranges = Ranges::Custom(&[(48..=57), (45..=46)]);
+
+            let test = acquire(&[R, Ranges::Capital]).collect::<Vec<_>>();
+
+            let proof = proof(&[(48..=57), (45..=46), (65..=90)]);
+            assert_eq!(proof, test);
+        }
+
+        mod acquire {
+            use super::*;
+
+            #[test]
+            fn basic_test() {
+                let test = acquire(&[Ranges::Letters, Ranges::Symbols]);
+                assert_eq!(len!(&LETTERS) + len!(&SYMBOLS), test.len());
+
+                let mut proof = proof(&LETTERS);
+                proof.extend(super::proof(&SYMBOLS));
+
+                assert_eq!(proof, test.collect::<Vec<_>>());
+            }
+
+            #[test]
+            fn reversed() {
+                let test = acquire(&[Ranges::Letters, Ranges::Symbols]);
+
+                let mut proof = proof(&LETTERS);
+                proof.extend(super::proof(&SYMBOLS));
+                proof.reverse();
+
+                assert_eq!(proof, test.rev().collect::<Vec<_>>());
+            }
+
+            #[test]
+            fn both_ends() {
+                let mut test = acquire(&[Ranges::Digits]);
+
+                for i in 0..5 {
+                    assert_eq!(b'0' + i, test.next().unwrap().code());
+                    assert_eq!(b'9' - i, test.next_back().unwrap().code());
+                }
+
+                assert_eq!(0, test.len());
+                assert_eq!(None, test.next());
+                assert_eq!(None, test.next_back());
+            }
+
+            #[test]
+            fn empty() {
+                let mut test = acquire(&[]);
+                assert_eq!(0, test.len());
+                assert_eq!(None, test.next());
+            }
+        }
+
+        #[test]
+        fn acquire_apart_test() {
+            let rs = [Ranges::Letters, Ranges::Symbols];
+            let test = acquire_apart(&rs);
+
+            assert_eq!(2, test.len());
+
+            for (t, r) in test.zip(rs.iter()) {
+                assert_eq!(proof(ranges_fn(r.clone())), t.collect::<Vec<_>>());
+            }
+
+            let mut test = acquire_apart(&rs);
+            let last = test.next_back().unwrap();
+            assert_eq!(proof(&SYMBOLS), last.collect::<Vec<_>>());
+        }
+    }
+}
+
+/// Module contains set of ASCII codes and set algebra over it.
+pub mod set {
+
+    use core::iter::FusedIterator;
+    use core::ops::{BitAnd, BitOr, BitXor, Not, RangeInclusive, Sub};
+    use crate::code::{Code, CODES};
+    use crate::ranges::{ranges, Ranges};
+
+    /// Set of ASCII codes backed by `u128` mask, bit `n` stands for code `n`.
+    ///
+    /// ```
+    /// use huski_lib_core::{set::CodeSet, ranges::Ranges};
+    ///
+    /// let vowels = CodeSet::from_ranges(&[65..=65, 69..=69, 73..=73, 79..=79, 85..=85]);
+    /// let consonants = CodeSet::of(Ranges::Capital).difference(vowels);
+    ///
+    /// assert_eq!(21, consonants.len());
+    /// assert!(consonants.contains(b'B'));
+    /// assert!(!consonants.contains(b'E'));
+    /// ```
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+    pub struct CodeSet {
+        mask: u128,
+    }
+
+    impl CodeSet {
+        /// Set of no code.
+        pub const EMPTY: CodeSet = CodeSet { mask: 0 };
+        /// Set of whole table.
+        pub const FULL: CodeSet = CodeSet { mask: u128::MAX };
+
+        /// Provides set for demand of `r`.
+        pub const fn of(r: Ranges) -> Self {
+            Self::from_ranges(ranges(r))
+        }
+
+        /// Provides set of codes from `rs`.
+        ///
+        /// Input values must fit into range 0-127, otherwise
+        /// function will panic.
+        pub const fn from_ranges(rs: &[RangeInclusive<usize>]) -> Self {
+            let mut mask = 0;
+
+            let mut ix = 0;
+            while ix < rs.len() {
+                let (start, end) = (*rs[ix].start(), *rs[ix].end());
+                let mut c = start;
+                while c <= end {
+                    assert!(c < 128, "value out of table range 0-127");
+                    mask |= 1 << c;
+                    c += 1;
+                }
+
+                ix += 1;
+            }
+
+            CodeSet { mask }
+        }
+
+        /// Provides set for `mask`, bit `n` stands for code `n`.
+        pub const fn from_mask(mask: u128) -> Self {
+            CodeSet { mask }
+        }
+
+        /// Mask of set, bit `n` stands for code `n`.
+        pub const fn mask(&self) -> u128 {
+            self.mask
+        }
+
+        /// Checks whether `c` is member of set.
+        pub const fn contains(&self, c: u8) -> bool {
+            c < 128 && self.mask & (1 << c) != 0
+        }
+
+        /// Adds `c` into set. Returns `false` if `c` was already member.
+        ///
+        /// Input value must fit into range 0-127, otherwise
+        /// function will panic.
+        pub fn insert(&mut self, c: u8) -> bool {
+            assert!(c < 128, "value out of table range 0-127");
+            let absent = !self.contains(c);
+            self.mask |= 1 << c;
+            absent
+        }
+
+        /// Removes `c` from set. Returns `false` if `c` was not member.
+        pub fn remove(&mut self, c: u8) -> bool {
+            let present = self.contains(c);
+            if present {
+                self.mask &= !(1 << c);
+            }
+            present
+        }
+
+        /// Count of members.
+        pub const fn len(&self) -> usize {
+            self.mask.count_ones() as usize
+        }
+
+        /// Checks whether set has no member.
+        pub const fn is_empty(&self) -> bool {
+            self.mask == 0
+        }
+
+        /// Codes of both sets.
+        pub const fn union(self, other: Self) -> Self {
+            CodeSet {
+                mask: self.mask | other.mask,
+            }
+        }
+
+        /// Codes common to both sets.
+        pub const fn intersection(self, other: Self) -> Self {
+            CodeSet {
+                mask: self.mask & other.mask,
+            }
+        }
+
+        /// Codes of this set not in `other`.
+        pub const fn difference(self, other: Self) -> Self {
+            CodeSet {
+                mask: self.mask & !other.mask,
+            }
+        }
+
+        /// Codes of either set but not of both.
+        pub const fn symmetric_difference(self, other: Self) -> Self {
+            CodeSet {
+                mask: self.mask ^ other.mask,
+            }
+        }
+
+        /// Codes of table not in this set.
+        pub const fn complement(self) -> Self {
+            CodeSet { mask: !self.mask }
+        }
+
+        /// Checks whether all members are also members of `other`.
+        pub const fn is_subset(&self, other: &Self) -> bool {
+            self.mask & !other.mask == 0
+        }
+
+        /// Iterates members in ascending order.
+        pub const fn iter(&self) -> Iter {
+            Iter { mask: self.mask }
+        }
+
+        /// Iterates `Code`s of members in ascending order.
+        pub fn codes(&self) -> impl DoubleEndedIterator<Item = &'static Code> + ExactSizeIterator {
+            self.iter().map(|c| &CODES[c as usize])
+        }
+
+        /// Iterates minimal listing of `RangeInclusive<usize>` covering set,
+        /// in ascending order.
+        /// ```
+        /// use huski_lib_core::{set::CodeSet, ranges::{Ranges, CONTROL}};
+        ///
+        /// let set = CodeSet::of(Ranges::Control);
+        /// assert!(set.runs().eq(CONTROL.iter().cloned()));
+        /// ```
+        pub const fn runs(&self) -> Runs {
+            Runs { mask: self.mask }
+        }
+    }
+
+    impl From<Ranges> for CodeSet {
+        fn from(r: Ranges) -> Self {
+            Self::of(r)
+        }
+    }
+
+    impl FromIterator<u8> for CodeSet {
+        /// Input values must fit into range 0-127, otherwise
+        /// function will panic.
+        fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self {
+            let mut set = CodeSet::EMPTY;
+            for c in iter {
+                set.insert(c);
+            }
+            set
+        }
+    }
+
+    impl IntoIterator for CodeSet {
+        type Item = u8;
+        type IntoIter = Iter;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+    impl BitOr for CodeSet {
+        type Output = Self;
+
+        fn bitor(self, rhs: Self) -> Self::Output {
+            self.union(rhs)
+        }
+    }
+
+    impl BitAnd for CodeSet {
+        type Output = Self;
+
+        fn bitand(self, rhs: Self) -> Self::Output {
+            self.intersection(rhs)
+        }
+    }
+
+    impl Sub for CodeSet {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            self.difference(rhs)
+        }
+    }
+
+    impl BitXor for CodeSet {
+        type Output = Self;
+
+        fn bitxor(self, rhs: Self) -> Self::Output {
+            self.symmetric_difference(rhs)
+        }
+    }
+
+    impl Not for CodeSet {
+        type Output = Self;
+
+        fn not(self) -> Self::Output {
+            self.complement()
+        }
+    }
+
+    /// Iterator over `CodeSet` members.
+    #[derive(Clone, Debug)]
+    pub struct Iter {
+        mask: u128,
+    }
+
+    impl Iterator for Iter {
+        type Item = u8;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.mask == 0 {
+                return None;
+            }
+
+            let c = self.mask.trailing_zeros();
+            self.mask &= !(1 << c);
+
+            Some(c as u8)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let len = self.mask.count_ones() as usize;
+            (len, Some(len))
+        }
+    }
+
+    impl DoubleEndedIterator for Iter {
+        fn next_back(&mut self) -> Option<Self::Item> {
+            if self.mask == 0 {
+                return None;
+            }
+
+            let c = 127 - self.mask.leading_zeros();
+            self.mask &= !(1 << c);
+
+            Some(c as u8)
+        }
+    }
+
+    impl ExactSizeIterator for Iter {}
+
+    impl FusedIterator for Iter {}
+
+    /// Iterator over `CodeSet` runs.
+    #[derive(Clone, Debug)]
+    pub struct Runs {
+        mask: u128,
+    }
+
+    impl Iterator for Runs {
+        type Item = RangeInclusive<usize>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.mask == 0 {
+                return None;
+            }
+
+            let start = self.mask.trailing_zeros();
+            let len = (self.mask >> start).trailing_ones();
+            let end = start + len - 1;
+
+            if end == 127 {
+                self.mask = 0;
+            } else {
+                self.mask &= !0 << (end + 1);
+            }
+
+            Some(start as usize..=end as usize)
+        }
+    }
+
+    impl FusedIterator for Runs {}
+
+    #[cfg(test)]
+    mod tests_of_units {
+
+        extern crate std;
+        use std::{vec, vec::Vec};
+        use super::*;
+        use crate::ranges::{LETTERS, SYMBOLS, TABLE};
+        use huski_auxies::{ccr1, ccr2, len};
+
+        fn members(s: CodeSet) -> Vec<usize> {
+            s.iter().map(|c| c as usize).collect()
+        }
+
+        #[test]
+        fn of() {
+            assert_eq!(ccr2!(&LETTERS), members(CodeSet::of(Ranges::Letters)));
+            assert_eq!(ccr2!(&SYMBOLS), members(CodeSet::of(Ranges::Symbols)));
+            assert_eq!(CodeSet::FULL, CodeSet::of(Ranges::Table));
+
+            const DIGITS: CodeSet = CodeSet::of(Ranges::Digits);
+            assert_eq!(0x3ff << 48, DIGITS.mask());
+
+            const CUSTOM: CodeSet = CodeSet::of(Ranges::Custom(&[(0..=1), (127..=127)]));
+            assert_eq!(vec![0, 1, 127], members(CUSTOM));
+        }
+
+        mod from_ranges {
+            use super::*;
+
+            #[test]
+            fn basic_test() {
+                let test = CodeSet::from_ranges(&[(0..=2), (125..=127), (1..=1)]);
+                assert_eq!(vec![0, 1, 2, 125, 126, 127], members(test));
+            }
+
+            #[test]
+            #[should_panic(expected = "value out of table range 0-127")]
+            fn index_128() {
+                _ = CodeSet::from_ranges(&[127..=128]);
+            }
+        }
+
+        #[test]
+        fn insert_remove() {
+            let mut test = CodeSet::EMPTY;
+
+            assert!(test.insert(5));
+            assert!(!test.insert(5));
+            assert!(test.contains(5));
+            assert_eq!(1, test.len());
+
+            assert!(test.remove(5));
+            assert!(!test.remove(5));
+            assert!(!test.remove(200));
+            assert!(test.is_empty());
+        }
+
+        #[test]
+        fn contains() {
+            let test = CodeSet::FULL;
+            assert!(test.contains(0));
+            assert!(test.contains(127));
+            assert!(!test.contains(128));
+        }
+
+        #[test]
+        fn algebra() {
+            let letters = CodeSet::of(Ranges::Letters);
+            let capital = CodeSet::of(Ranges::Capital);
+            let small = CodeSet::of(Ranges::Small);
+            let control = CodeSet::of(Ranges::Control);
+            let printable = CodeSet::of(Ranges::Printable);
+
+            assert_eq!(letters, capital.union(small));
+            assert_eq!(letters, capital | small);
+            assert_eq!(capital, letters.intersection(capital));
+            assert_eq!(capital, letters & capital);
+            assert_eq!(small, letters.difference(capital));
+            assert_eq!(small, letters - capital);
+            assert_eq!(control, printable.complement());
+            assert_eq!(control, !printable);
+            assert_eq!(small, letters.symmetric_difference(capital));
+            assert_eq!(small, letters ^ capital);
+            assert!(capital.is_subset(&letters));
+            assert!(!letters.is_subset(&capital));
+        }
+
+        #[test]
+        fn iter() {
+            let test = CodeSet::of(Ranges::Control);
+            let proof = ccr1!(0..=31, 127..=127);
+
+            assert_eq!(33, test.iter().len());
+            assert_eq!(proof, members(test));
+
+            let mut rev = test.iter().rev().map(|c| c as usize).collect::<Vec<_>>();
+            rev.reverse();
+            assert_eq!(proof, rev);
+        }
+
+        #[test]
+        fn codes() {
+            let test = CodeSet::of(Ranges::Digits);
+            let proof = CODES[48..=57].iter().collect::<Vec<_>>();
+            assert_eq!(proof, test.codes().collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn runs() {
+            for r in [
+                Ranges::Control,
+                Ranges::Letters,
+                Ranges::Symbols,
+                Ranges::Table,
+            ] {
+                let test = CodeSet::of(r.clone()).runs().collect::<Vec<_>>();
+                assert_eq!(ranges(r), test.as_slice());
+            }
+
+            let test = CodeSet::from_ranges(&[(0..=0), (1..=3), (5..=5)]);
+            assert_eq!(vec![(0..=3), (5..=5)], test.runs().collect::<Vec<_>>());
+            assert_eq!(0, CodeSet::EMPTY.runs().count());
+            assert_eq!(len!(&TABLE), CodeSet::FULL.iter().len());
+        }
+
+        #[test]
+        fn from_iter() {
+            let test = CodeSet::from_iter([3, 1, 3]);
+            assert_eq!(vec![1, 3], members(test));
+        }
+    }
+}
+
+/// Module contains per-code classification by `Ranges`.
+///
+/// Classification is backed by 128-entry property table, thus each
+/// predicate is single lookup.
+pub mod class {
+
+    use crate::ranges::{ranges, Ranges};
+
+    /// Property table, bit of `const fn bit()` set for each `Ranges` code falls into.
+    static PROPERTIES: [u32; 128] = properties();
+
+    const VARIANTS_LEN: usize = 20;
+
+    const fn bit(r: &Ranges) -> u32 {
+        let ix = match r {
+            | Ranges::Printable => 0,
+            | Ranges::Control => 1,
+            | Ranges::Capital => 2,
+            | Ranges::Small => 3,
+            | Ranges::Letters => 4,
+            | Ranges::Digits => 5,
+            | Ranges::Symbols => 6,
+            | Ranges::Table => 7,
+            | Ranges::HexDigits => 8,
+            | Ranges::Whitespace => 9,
+            | Ranges::Blank => 10,
+            | Ranges::Alphanumeric => 11,
+            | Ranges::Punctuation => 12,
+            | Ranges::Graphic => 13,
+            | Ranges::Word => 14,
+            | Ranges::Transmission => 15,
+            | Ranges::FormatEffectors => 16,
+            | Ranges::DeviceControls => 17,
+            | Ranges::InformationSeparators => 18,
+            | Ranges::CodeExtension => 19,
+            // custom ranges are not part of property table
+            | Ranges::Custom(_) => return 0,
+        };
+
+        1 << ix
+    }
+
+    /// Inverse of `const fn bit()`.
+    const fn variant(ix: usize) -> Ranges {
+        match ix {
+            | 0 => Ranges::Printable,
+            | 1 => Ranges::Control,
+            | 2 => Ranges::Capital,
+            | 3 => Ranges::Small,
+            | 4 => Ranges::Letters,
+            | 5 => Ranges::Digits,
+            | 6 => Ranges::Symbols,
+            | 7 => Ranges::Table,
+            | 8 => Ranges::HexDigits,
+            | 9 => Ranges::Whitespace,
+            | 10 => Ranges::Blank,
+            | 11 => Ranges::Alphanumeric,
+            | 12 => Ranges::Punctuation,
+            | 13 => Ranges::Graphic,
+            | 14 => Ranges::Word,
+            | 15 => Ranges::Transmission,
+            | 16 => Ranges::FormatEffectors,
+            | 17 => Ranges::DeviceControls,
+            | 18 => Ranges::InformationSeparators,
+            | 19 => Ranges::CodeExtension,
+            | _ => panic!("no such variant"),
+        }
+    }
+
+    const fn properties() -> [u32; 128] {
+        let mut props = [0; 128];
+
+        let mut vix = 0;
+        while vix < VARIANTS_LEN {
+            let bit = 1 << vix;
+            let rs = ranges(variant(vix));
+
+            let mut rix = 0;
+            while rix < rs.len() {
+                let mut c = *rs[rix].start();
+                while c <= *rs[rix].end() {
+                    props[c] |= bit;
+                    c += 1;
+                }
+
+                rix += 1;
+            }
+
+            vix += 1;
+        }
+
+        props
+    }
+
+    /// Checks whether `c` falls into `r`.
+    ///
+    /// Values out of table range 0-127 fall into none.
+    /// `Ranges::Custom` is not backed by property table
+    /// thus it is checked by search.
+    /// ```
+    /// use huski_lib_core::{class::is, ranges::Ranges};
+    ///
+    /// assert!(is(Ranges::Symbols, b' '));
+    /// assert!(!is(Ranges::Punctuation, b' '));
+    /// assert!(!is(Ranges::Table, 128));
+    /// ```
+    pub const fn is(r: Ranges, c: u8) -> bool {
+        if let Ranges::Custom(rs) = r {
+            let mut ix = 0;
+            while ix < rs.len() {
+                if *rs[ix].start() <= c as usize && c as usize <= *rs[ix].end() {
+                    return c < 128;
+                }
+
+                ix += 1;
+            }
+
+            return false;
+        }
+
+        c < 128 && PROPERTIES[c as usize] & bit(&r) != 0
+    }
+
+    /// Checks whether `c` falls into `r`.
+    ///
+    /// Chars out of table range 0-127 fall into none.
+    pub const fn is_char(r: Ranges, c: char) -> bool {
+        (c as u32) < 128 && is(r, c as u8)
+    }
+
+    macro_rules! predicates {
+        ($($r:ident, $name:literal, $fn:ident, $fn_char:ident;)*) => {
+            $(
+                #[doc = concat!("Checks whether `c` is ", $name, " code. See `Ranges::", stringify!($r), "`.")]
+                pub const fn $fn(c: u8) -> bool {
+                    is(Ranges::$r, c)
+                }
+
+                #[doc = concat!("Checks whether `c` is ", $name, " code. See `Ranges::", stringify!($r), "`.")]
+                pub const fn $fn_char(c: char) -> bool {
+                    is_char(Ranges::$r, c)
+                }
+            )*
+        };
+    }
+
+    predicates! {
+        Printable, "printable", is_printable, is_printable_char;
+        Control, "control", is_control, is_control_char;
+        Capital, "capital letter", is_capital, is_capital_char;
+        Small, "small letter", is_small, is_small_char;
+        Letters, "letter", is_letter, is_letter_char;
+        Digits, "digit", is_digit, is_digit_char;
+        Symbols, "symbol", is_symbol, is_symbol_char;
+        Table, "table", is_table, is_table_char;
+        HexDigits, "hexadecimal digit", is_hex_digit, is_hex_digit_char;
+        Whitespace, "whitespace", is_whitespace, is_whitespace_char;
+        Blank, "blank", is_blank, is_blank_char;
+        Alphanumeric, "alphanumeric", is_alphanumeric, is_alphanumeric_char;
+        Punctuation, "punctuation", is_punctuation, is_punctuation_char;
+        Graphic, "graphic", is_graphic, is_graphic_char;
+        Word, "word", is_word, is_word_char;
+        Transmission, "transmission control", is_transmission, is_transmission_char;
+        FormatEffectors, "format effector", is_format_effector, is_format_effector_char;
+        DeviceControls, "device control", is_device_control, is_device_control_char;
+        InformationSeparators, "information separator", is_information_separator, is_information_separator_char;
+        CodeExtension, "code extension", is_code_extension, is_code_extension_char;
+    }
+
+    #[cfg(test)]
+    mod tests_of_units {
+
+        use super::*;
+
+        #[test]
+        fn properties() {
+            for ix in 0..VARIANTS_LEN {
+                let v = variant(ix);
+                for c in 0..=255u8 {
+                    let proof = ranges(v.clone()).iter().any(|r| r.contains(&(c as usize)));
+                    assert_eq!(proof, is(v.clone(), c), "{:?} {}", v, c);
+                }
+            }
+        }
+
+        #[test]
+        fn bits() {
+            assert_eq!(Ranges::ALL.len(), VARIANTS_LEN);
+
+            let mut all = 0;
+            for r in Ranges::ALL.iter() {
+                let b = bit(r);
+                assert_eq!(1, b.count_ones(), "{:?}", r);
+                assert_eq!(*r, variant(b.trailing_zeros() as usize));
+
+                all |= b;
+            }
+
+            // distinct bits, one per variant
+            assert_eq!(VARIANTS_LEN as u32, all.count_ones());
+        }
+
+        #[test]
+        fn custom() {
+            let r = Ranges::Custom(&[(1..=3), (200..=210)]);
+
+            assert!(!is(r.clone(), 0));
+            assert!(is(r.clone(), 1));
+            assert!(is(r.clone(), 3));
+            assert!(!is(r.clone(), 4));
+            assert!(!is(r.clone(), 205));
+            assert!(is_char(r, '\x02'));
+
+            assert_eq!(0, bit(&Ranges::Custom(&[])));
+        }
+
+        #[test]
+        fn is_char_test() {
+            assert!(is_char(Ranges::Control, '\x7f'));
+            assert!(!is_char(Ranges::Control, '\u{80}'));
+            assert!(!is_char(Ranges::Table, '\u{100}'));
+        }
+
+        #[test]
+        fn predicates() {
+            for c in 0..=255u8 {
+                assert_eq!(c.is_ascii_control(), is_control(c));
+                assert_eq!(c.is_ascii_uppercase(), is_capital(c));
+                assert_eq!(c.is_ascii_lowercase(), is_small(c));
+                assert_eq!(c.is_ascii_alphabetic(), is_letter(c));
+                assert_eq!(c.is_ascii_digit(), is_digit(c));
+                assert_eq!(c.is_ascii(), is_table(c));
+                assert_eq!(c.is_ascii_hexdigit(), is_hex_digit(c));
+                assert_eq!(c.is_ascii_alphanumeric(), is_alphanumeric(c));
+                assert_eq!(c.is_ascii_punctuation(), is_punctuation(c));
+                assert_eq!(c.is_ascii_graphic(), is_graphic(c));
+
+                let ch = c as char;
+                assert_eq!(is_printable(c), is_printable_char(ch));
+                assert_eq!(is_symbol(c), is_symbol_char(ch));
+                assert_eq!(is_whitespace(c), is_whitespace_char(ch));
+                assert_eq!(is_blank(c), is_blank_char(ch));
+                assert_eq!(is_word(c), is_word_char(ch));
+            }
+
+            assert!(is_symbol(b' '));
+            assert!(is_whitespace(0x0b));
+            assert!(!is_blank(b'\n'));
+            assert!(is_word(b'_'));
+            assert!(is_transmission(0x15));
+            assert!(is_format_effector(b'\n'));
+            assert!(is_device_control(0x13));
+            assert!(is_information_separator(0x1f));
+            assert!(is_code_extension(0x1b));
+            assert!(!is_code_extension(0x7f));
+        }
+
+        #[test]
+        fn const_use() {
+            const DIGITS: [bool; 2] = [is_digit(b'7'), is_digit(b'a')];
+            assert_eq!([true, false], DIGITS);
+        }
+    }
+}
+
+/// Module contains ASCII case mapping, folding and case-insensitive comparison.
+///
+/// Mapping is defined by `Ranges::Capital` and `Ranges::Small`, values out of
+/// them, including those out of table range 0-127, are left intact.
+pub mod case {
+
+    use core::cmp::Ordering;
+    use core::hash::{Hash, Hasher};
+    use crate::class::{is_capital, is_small};
+    use crate::code::{Code, CODES};
+    use crate::ranges::{CAPITAL, SMALL};
+
+    /// Distance between capital and small letter of same kind.
+    const DISTANCE: u8 = (*SMALL[0].start() - *CAPITAL[0].start()) as u8;
+
+    /// Maps small letter to capital one.
+    /// ```
+    /// use huski_lib_core::case::to_upper;
+    ///
+    /// assert_eq!(b'A', to_upper(b'a'));
+    /// assert_eq!(b'{', to_upper(b'{'));
+    /// ```
+    pub const fn to_upper(c: u8) -> u8 {
+        if is_small(c) {
+            c - DISTANCE
+        } else {
+            c
+        }
+    }
+
+    /// Maps capital letter to small one.
+    pub const fn to_lower(c: u8) -> u8 {
+        if is_capital(c) {
+            c + DISTANCE
+        } else {
+            c
+        }
+    }
+
+    /// Case folding, i.e. mapping used for case-insensitive comparison.
+    ///
+    /// Folds to small letters.
+    pub const fn fold(c: u8) -> u8 {
+        to_lower(c)
+    }
+
+    /// Case mapping acquisition.
+    impl Code {
+        /// Capital letter for small one, otherwise `self`.
+        /// ```
+        /// use huski_lib_core::code::CODES;
+        ///
+        /// assert_eq!("Q", CODES[b'q' as usize].to_upper().human());
+        /// ```
+        pub const fn to_upper(&self) -> &'static Code {
+            &CODES[to_upper(self.code()) as usize]
+        }
+
+        /// Small letter for capital one, otherwise `self`.
+        pub const fn to_lower(&self) -> &'static Code {
+            &CODES[to_lower(self.code()) as usize]
+        }
+    }
+
+    /// Maps all small letters of `bs` to capital ones, in place.
+    /// ```
+    /// use huski_lib_core::case::make_upper;
+    ///
+    /// let mut header = *b"content-type";
+    /// make_upper(&mut header);
+    /// assert_eq!(b"CONTENT-TYPE", &header);
+    /// ```
+    pub fn make_upper(bs: &mut [u8]) {
+        for b in bs.iter_mut() {
+            *b = to_upper(*b);
+        }
+    }
+
+    /// Maps all capital letters of `bs` to small ones, in place.
+    pub fn make_lower(bs: &mut [u8]) {
+        for b in bs.iter_mut() {
+            *b = to_lower(*b);
+        }
+    }
+
+    /// Lazily maps `bs` to capital letters.
+    pub fn upper(bs: &[u8]) -> impl DoubleEndedIterator<Item = u8> + ExactSizeIterator + '_ {
+        bs.iter().map(|&x| to_upper(x))
+    }
+
+    /// Lazily maps `bs` to small letters.
+    pub fn lower(bs: &[u8]) -> impl DoubleEndedIterator<Item = u8> + ExactSizeIterator + '_ {
+        bs.iter().map(|&x| to_lower(x))
+    }
+
+    /// Checks whether `a` and `b` equal after case folding.
+    /// ```
+    /// use huski_lib_core::case::eq_ignore_case;
+    ///
+    /// assert!(eq_ignore_case(b"Content-Length", b"content-length"));
+    /// ```
+    pub const fn eq_ignore_case(a: &[u8], b: &[u8]) -> bool {
+        if a.len() != b.len() {
+            return false;
+        }
+
+        let mut ix = 0;
+        while ix < a.len() {
+            if fold(a[ix]) != fold(b[ix]) {
+                return false;
+            }
+
+            ix += 1;
+        }
+
+        true
+    }
+
+    /// Orders `a` and `b` lexicographically after case folding.
+    pub const fn cmp_ignore_case(a: &[u8], b: &[u8]) -> Ordering {
+        let mut ix = 0;
+        while ix < a.len() && ix < b.len() {
+            let (a, b) = (fold(a[ix]), fold(b[ix]));
+            if a < b {
+                return Ordering::Less;
+            }
+
+            if a > b {
+                return Ordering::Greater;
+            }
+
+            ix += 1;
+        }
+
+        if a.len() < b.len() {
+            Ordering::Less
+        } else if a.len() > b.len() {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// Wrapper comparing and hashing its value case-insensitively.
+    ///
+    /// Suitable as key of maps and sets, i.e. for HTTP header names.
+    /// ```
+    /// use huski_lib_core::case::NoCase;
+    ///
+    /// assert_eq!(NoCase("Host"), NoCase("HOST"));
+    /// assert!(NoCase("accept") < NoCase("Age"));
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    pub struct NoCase<T>(pub T);
+
+    impl<T: AsRef<[u8]>> PartialEq for NoCase<T> {
+        fn eq(&self, other: &Self) -> bool {
+            eq_ignore_case(self.0.as_ref(), other.0.as_ref())
+        }
+    }
+
+    impl<T: AsRef<[u8]>> Eq for NoCase<T> {}
+
+    impl<T: AsRef<[u8]>> PartialOrd for NoCase<T> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl<T: AsRef<[u8]>> Ord for NoCase<T> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            cmp_ignore_case(self.0.as_ref(), other.0.as_ref())
+        }
+    }
+
+    impl<T: AsRef<[u8]>> Hash for NoCase<T> {
+        /// Hashes folded bytes, consistently with `PartialEq`.
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            let bs = self.0.as_ref();
+            state.write_usize(bs.len());
+            for &b in bs {
+                state.write_u8(fold(b));
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests_of_units {
+
+        extern crate std;
+        use std::{collections::HashSet, vec::Vec};
+        use super::*;
+
+        #[test]
+        fn mapping() {
+            for c in 0..=255u8 {
+                assert_eq!(c.to_ascii_uppercase(), to_upper(c));
+                assert_eq!(c.to_ascii_lowercase(), to_lower(c));
+                assert_eq!(to_lower(c), fold(c));
+            }
+        }
+
+        #[test]
+        fn code() {
+            assert_eq!(&CODES[b'A' as usize], CODES[b'a' as usize].to_upper());
+            assert_eq!(&CODES[b'a' as usize], CODES[b'A' as usize].to_lower());
+            assert_eq!(&CODES[b'@' as usize], CODES[b'@' as usize].to_lower());
+            assert_eq!(&CODES[27], CODES[27].to_upper());
+        }
+
+        #[test]
+        fn make() {
+            let mut test = *b"Ab1-\x80z";
+            make_upper(&mut test);
+            assert_eq!(b"AB1-\x80Z", &test);
+
+            make_lower(&mut test);
+            assert_eq!(b"ab1-\x80z", &test);
+        }
+
+        #[test]
+        fn lazy() {
+            assert_eq!(b"ABC[", upper(b"aBc[").collect::<Vec<u8>>().as_slice());
+            assert_eq!(b"abc[", lower(b"aBc[").collect::<Vec<u8>>().as_slice());
+        }
+
+        #[test]
+        fn eq_ignore_case_test() {
+            assert!(eq_ignore_case(b"", b""));
+            assert!(eq_ignore_case(b"X-Forwarded-For", b"x-forwarded-FOR"));
+            assert!(!eq_ignore_case(b"ab", b"abc"));
+            assert!(!eq_ignore_case(b"@", b"`"));
+            assert!(!eq_ignore_case(b"\xc0", b"\xe0"));
+        }
+
+        #[test]
+        fn cmp_ignore_case_test() {
+            assert_eq!(Ordering::Equal, cmp_ignore_case(b"Esc", b"eSC"));
+            assert_eq!(Ordering::Less, cmp_ignore_case(b"ES", b"esc"));
+            assert_eq!(Ordering::Greater, cmp_ignore_case(b"z", b"A"));
+            assert_eq!(Ordering::Greater, cmp_ignore_case(b"Z", b"_"));
+        }
+
+        #[test]
+        fn no_case() {
+            let mut set = HashSet::new();
+            assert!(set.insert(NoCase("Content-Type")));
+            assert!(!set.insert(NoCase("content-type")));
+            assert!(set.insert(NoCase("Content-Length")));
+            assert_eq!(2, set.len());
+
+            let mut test = [NoCase("b"), NoCase("A"), NoCase("a"), NoCase("C")];
+            test.sort();
+            let test = test.iter().map(|x| x.0).collect::<Vec<_>>();
+            assert_eq!(["A", "a", "b", "C"], test.as_slice());
+        }
+    }
+}
+
+/// Module contains notations of ASCII codes, indexed by code value.
+pub mod notation {
+
+    /// Caret notation, control codes only
+    pub static CARET: [Option<&str>; 128] = [
+        Some("^@"),
+        Some("^A"),
+        Some("^B"),
+        Some("^C"),
+        Some("^D"),
+        Some("^E"),
+        Some("^F"),
+        Some("^G"),
+        Some("^H"),
+        Some("^I"),
+        Some("^J"),
+        Some("^K"),
+        Some("^L"),
+        Some("^M"),
+        Some("^N"),
+        Some("^O"),
+        Some("^P"),
+        Some("^Q"),
+        Some("^R"),
+        Some("^S"),
+        Some("^T"),
+        Some("^U"),
+        Some("^V"),
+        Some("^W"),
+        Some("^X"),
+        Some("^Y"),
+        Some("^Z"),
+        Some("^["),
+        Some("^\\"),
+        Some("^]"),
+        Some("^^"),
+        Some("^_"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("^?"),
+    ];
+
+    /// C escape sequence, printable codes as they are except quotes and reverse solidus
+    pub static C_ESCAPE: [&str; 128] = [
+        "\\0", "\\x01", "\\x02", "\\x03", "\\x04", "\\x05", "\\x06", "\\a", "\\b", "\\t", "\\n",
+        "\\v", "\\f", "\\r", "\\x0e", "\\x0f", "\\x10", "\\x11", "\\x12", "\\x13", "\\x14",
+        "\\x15", "\\x16", "\\x17", "\\x18", "\\x19", "\\x1a", "\\x1b", "\\x1c", "\\x1d", "\\x1e",
+        "\\x1f", " ", "!", "\\\"", "#", "$", "%", "&", "\\'", "(", ")", "*", "+", ",", "-", ".",
+        "/", "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", ":", ";", "<", "=", ">", "?", "@",
+        "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q", "R",
+        "S", "T", "U", "V", "W", "X", "Y", "Z", "[", "\\\\", "]", "^", "_", "`", "a", "b", "c",
+        "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r", "s", "t", "u",
+        "v", "w", "x", "y", "z", "{", "|", "}", "~", "\\x7f",
+    ];
+
+    /// Rust escape sequence, printable codes as they are except quotes and reverse solidus
+    pub static RUST_ESCAPE: [&str; 128] = [
+        "\\0", "\\x01", "\\x02", "\\x03", "\\x04", "\\x05", "\\x06", "\\x07", "\\x08", "\\t",
+        "\\n", "\\x0b", "\\x0c", "\\r", "\\x0e", "\\x0f", "\\x10", "\\x11", "\\x12", "\\x13",
+        "\\x14", "\\x15", "\\x16", "\\x17", "\\x18", "\\x19", "\\x1a", "\\x1b", "\\x1c", "\\x1d",
+        "\\x1e", "\\x1f", " ", "!", "\\\"", "#", "$", "%", "&", "\\'", "(", ")", "*", "+", ",",
+        "-", ".", "/", "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", ":", ";", "<", "=", ">",
+        "?", "@", "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P",
+        "Q", "R", "S", "T", "U", "V", "W", "X", "Y", "Z", "[", "\\\\", "]", "^", "_", "`", "a",
+        "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r", "s",
+        "t", "u", "v", "w", "x", "y", "z", "{", "|", "}", "~", "\\x7f",
+    ];
+
+    /// Unicode code point notation
+    pub static UNICODE: [&str; 128] = [
+        "U+0000", "U+0001", "U+0002", "U+0003", "U+0004", "U+0005", "U+0006", "U+0007", "U+0008",
+        "U+0009", "U+000A", "U+000B", "U+000C", "U+000D", "U+000E", "U+000F", "U+0010", "U+0011",
+        "U+0012", "U+0013", "U+0014", "U+0015", "U+0016", "U+0017", "U+0018", "U+0019", "U+001A",
+        "U+001B", "U+001C", "U+001D", "U+001E", "U+001F", "U+0020", "U+0021", "U+0022", "U+0023",
+        "U+0024", "U+0025", "U+0026", "U+0027", "U+0028", "U+0029", "U+002A", "U+002B", "U+002C",
+        "U+002D", "U+002E", "U+002F", "U+0030", "U+0031", "U+0032", "U+0033", "U+0034", "U+0035",
+        "U+0036", "U+0037", "U+0038", "U+0039", "U+003A", "U+003B", "U+003C", "U+003D", "U+003E",
+        "U+003F", "U+0040", "U+0041", "U+0042", "U+0043", "U+0044", "U+0045", "U+0046", "U+0047",
+        "U+0048", "U+0049", "U+004A", "U+004B", "U+004C", "U+004D", "U+004E", "U+004F", "U+0050",
+        "U+0051", "U+0052", "U+0053", "U+0054", "U+0055", "U+0056", "U+0057", "U+0058", "U+0059",
+        "U+005A", "U+005B", "U+005C", "U+005D", "U+005E", "U+005F", "U+0060", "U+0061", "U+0062",
+        "U+0063", "U+0064", "U+0065", "U+0066", "U+0067", "U+0068", "U+0069", "U+006A", "U+006B",
+        "U+006C", "U+006D", "U+006E", "U+006F", "U+0070", "U+0071", "U+0072", "U+0073", "U+0074",
+        "U+0075", "U+0076", "U+0077", "U+0078", "U+0079", "U+007A", "U+007B", "U+007C", "U+007D",
+        "U+007E", "U+007F",
+    ];
+
+    /// HTML numeric character reference
+    pub static HTML: [&str; 128] = [
+        "&#0;", "&#1;", "&#2;", "&#3;", "&#4;", "&#5;", "&#6;", "&#7;", "&#8;", "&#9;", "&#10;",
+        "&#11;", "&#12;", "&#13;", "&#14;", "&#15;", "&#16;", "&#17;", "&#18;", "&#19;", "&#20;",
+        "&#21;", "&#22;", "&#23;", "&#24;", "&#25;", "&#26;", "&#27;", "&#28;", "&#29;", "&#30;",
+        "&#31;", "&#32;", "&#33;", "&#34;", "&#35;", "&#36;", "&#37;", "&#38;", "&#39;", "&#40;",
+        "&#41;", "&#42;", "&#43;", "&#44;", "&#45;", "&#46;", "&#47;", "&#48;", "&#49;", "&#50;",
+        "&#51;", "&#52;", "&#53;", "&#54;", "&#55;", "&#56;", "&#57;", "&#58;", "&#59;", "&#60;",
+        "&#61;", "&#62;", "&#63;", "&#64;", "&#65;", "&#66;", "&#67;", "&#68;", "&#69;", "&#70;",
+        "&#71;", "&#72;", "&#73;", "&#74;", "&#75;", "&#76;", "&#77;", "&#78;", "&#79;", "&#80;",
+        "&#81;", "&#82;", "&#83;", "&#84;", "&#85;", "&#86;", "&#87;", "&#88;", "&#89;", "&#90;",
+        "&#91;", "&#92;", "&#93;", "&#94;", "&#95;", "&#96;", "&#97;", "&#98;", "&#99;", "&#100;",
+        "&#101;", "&#102;", "&#103;", "&#104;", "&#105;", "&#106;", "&#107;", "&#108;", "&#109;",
+        "&#110;", "&#111;", "&#112;", "&#113;", "&#114;", "&#115;", "&#116;", "&#117;", "&#118;",
+        "&#119;", "&#120;", "&#121;", "&#122;", "&#123;", "&#124;", "&#125;", "&#126;", "&#127;",
+    ];
+
+    /// HTML named character reference, where defined
+    pub static HTML_ENTITY: [Option<&str>; 128] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("&Tab;"),
+        Some("&NewLine;"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("&excl;"),
+        Some("&quot;"),
+        Some("&num;"),
+        Some("&dollar;"),
+        Some("&percnt;"),
+        Some("&amp;"),
+        Some("&apos;"),
+        Some("&lpar;"),
+        Some("&rpar;"),
+        Some("&ast;"),
+        Some("&plus;"),
+        Some("&comma;"),
+        None,
+        Some("&period;"),
+        Some("&sol;"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("&colon;"),
+        Some("&semi;"),
+        Some("&lt;"),
+        Some("&equals;"),
+        Some("&gt;"),
+        Some("&quest;"),
+        Some("&commat;"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("&lsqb;"),
+        Some("&bsol;"),
+        Some("&rsqb;"),
+        Some("&Hat;"),
+        Some("&lowbar;"),
+        Some("&grave;"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("&lcub;"),
+        Some("&verbar;"),
+        Some("&rcub;"),
+        None,
+        None,
+    ];
+
+    /// URL percent-encoding, uppercase hexadecimal digits
+    ///
+    /// Every code is encoded, even unreserved characters of RFC 3986, i.e.
+    /// `%41` for `A`. That is deliberate, encoding them is valid while
+    /// decoders treat both forms alike.
+    pub static PERCENT: [&str; 128] = [
+        "%00", "%01", "%02", "%03", "%04", "%05", "%06", "%07", "%08", "%09", "%0A", "%0B", "%0C",
+        "%0D", "%0E", "%0F", "%10", "%11", "%12", "%13", "%14", "%15", "%16", "%17", "%18", "%19",
+        "%1A", "%1B", "%1C", "%1D", "%1E", "%1F", "%20", "%21", "%22", "%23", "%24", "%25", "%26",
+        "%27", "%28", "%29", "%2A", "%2B", "%2C", "%2D", "%2E", "%2F", "%30", "%31", "%32", "%33",
+        "%34", "%35", "%36", "%37", "%38", "%39", "%3A", "%3B", "%3C", "%3D", "%3E", "%3F", "%40",
+        "%41", "%42", "%43", "%44", "%45", "%46", "%47", "%48", "%49", "%4A", "%4B", "%4C", "%4D",
+        "%4E", "%4F", "%50", "%51", "%52", "%53", "%54", "%55", "%56", "%57", "%58", "%59", "%5A",
+        "%5B", "%5C", "%5D", "%5E", "%5F", "%60", "%61", "%62", "%63", "%64", "%65", "%66", "%67",
+        "%68", "%69", "%6A", "%6B", "%6C", "%6D", "%6E", "%6F", "%70", "%71", "%72", "%73", "%74",
+        "%75", "%76", "%77", "%78", "%79", "%7A", "%7B", "%7C", "%7D", "%7E", "%7F",
+    ];
+
+    /// Unicode Control Pictures glyph, control codes and space only
+    pub static PICTURE: [Option<&str>; 128] = [
+        Some("␀"),
+        Some("␁"),
+        Some("␂"),
+        Some("␃"),
+        Some("␄"),
+        Some("␅"),
+        Some("␆"),
+        Some("␇"),
+        Some("␈"),
+        Some("␉"),
+        Some("␊"),
+        Some("␋"),
+        Some("␌"),
+        Some("␍"),
+        Some("␎"),
+        Some("␏"),
+        Some("␐"),
+        Some("␑"),
+        Some("␒"),
+        Some("␓"),
+        Some("␔"),
+        Some("␕"),
+        Some("␖"),
+        Some("␗"),
+        Some("␘"),
+        Some("␙"),
+        Some("␚"),
+        Some("␛"),
+        Some("␜"),
+        Some("␝"),
+        Some("␞"),
+        Some("␟"),
+        Some("␠"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("␡"),
+    ];
+
+    #[cfg(test)]
+    mod tests_of_units {
+
+        extern crate std;
+        use std::{format, string::ToString};
+        use super::*;
+
+        #[test]
+        fn caret() {
+            for (ix, c) in CARET.iter().enumerate() {
+                match ix {
+                    | 0..=31 | 127 => {
+                        let c = c.unwrap().as_bytes();
+                        assert_eq!(b'^', c[0]);
+                        assert_eq!(ix as u8 ^ 0x40, c[1]);
+                    },
+                    | _ => assert_eq!(None, *c),
+                }
+            }
+        }
+
+        #[test]
+        fn escapes() {
+            for ix in 0..128u8 {
+                let (c, rust) = (C_ESCAPE[ix as usize], RUST_ESCAPE[ix as usize]);
+                match ix {
+                    | 0 | 9 | 10 | 13 => assert_eq!(c, rust),
+                    | 7 | 8 | 11 | 12 => assert_eq!(format!("\\x{:02x}", ix), rust),
+                    | 0..=31 | 127 => {
+                        assert_eq!(format!("\\x{:02x}", ix), rust);
+                        assert_eq!(c, rust);
+                    },
+                    | b'\\' | b'\'' | b'"' => {
+                        assert_eq!(format!("\\{}", ix as char), rust);
+                        assert_eq!(c, rust);
+                    },
+                    | _ => {
+                        assert_eq!((ix as char).to_string(), rust);
+                        assert_eq!(c, rust);
+                    },
+                }
+            }
+
+            assert_eq!("\\0", C_ESCAPE[0]);
+            assert_eq!("\\a", C_ESCAPE[7]);
+            assert_eq!("\\b", C_ESCAPE[8]);
+            assert_eq!("\\v", C_ESCAPE[11]);
+            assert_eq!("\\f", C_ESCAPE[12]);
+            assert_eq!("\\x1b", C_ESCAPE[27]);
+        }
+
+        #[test]
+        fn numeric() {
+            for ix in 0..128 {
+                assert_eq!(format!("U+{:04X}", ix), UNICODE[ix]);
+                assert_eq!(format!("&#{};", ix), HTML[ix]);
+                assert_eq!(format!("%{:02X}", ix), PERCENT[ix]);
+            }
+        }
+
+        #[test]
+        fn html_entity() {
+            assert_eq!(Some("&amp;"), HTML_ENTITY[38]);
+            assert_eq!(Some("&lt;"), HTML_ENTITY[60]);
+            assert_eq!(None, HTML_ENTITY[65]);
+
+            for e in HTML_ENTITY.iter().flatten() {
+                assert!(e.starts_with('&') && e.ends_with(';'));
+            }
+        }
+
+        #[test]
+        fn picture() {
+            for (ix, p) in PICTURE.iter().enumerate() {
+                match ix {
+                    | 0..=32 => assert_eq!(
+                        char::from_u32(0x2400 + ix as u32),
+                        p.and_then(|x| x.chars().next())
+                    ),
+                    | 127 => assert_eq!(Some("\u{2421}"), *p),
+                    | _ => assert_eq!(None, *p),
+                }
+            }
+        }
+    }
+}
+
+/// Module contains naming schemes, i.e. canonical names of codes by various standards.
+pub mod naming {
+
+    /// Naming scheme.
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    pub enum Scheme {
+        /// Unicode character name, i.e. `LATIN CAPITAL LETTER A`
+        Unicode,
+        /// ISO 6429 control function name, i.e. `ESCAPE`
+        Iso6429,
+        /// RFC 1345 mnemonic, i.e. `EC` for ESC
+        Rfc1345,
+        /// Adobe Glyph List name, i.e. `controlESC` for ESC
+        Adobe,
+        /// X11 keysym name, i.e. `Escape` for ESC
+        X11,
+    }
+
+    impl Scheme {
+        /// All variants.
+        pub const ALL: [Scheme; 5] = [
+            Scheme::Unicode,
+            Scheme::Iso6429,
+            Scheme::Rfc1345,
+            Scheme::Adobe,
+            Scheme::X11,
+        ];
+
+        /// Name, suitable for identification.
+        /// ```
+        /// use huski_lib_core::naming::Scheme;
+        ///
+        /// assert_eq!("rfc1345", Scheme::Rfc1345.name());
+        /// ```
+        pub const fn name(&self) -> &'static str {
+            match self {
+                | Scheme::Unicode => "unicode",
+                | Scheme::Iso6429 => "iso6429",
+                | Scheme::Rfc1345 => "rfc1345",
+                | Scheme::Adobe => "adobe",
+                | Scheme::X11 => "x11",
+            }
+        }
+
+        /// Names of all codes, indexed by code value, `None` where scheme does not name code.
+        /// ```
+        /// use huski_lib_core::naming::Scheme;
+        ///
+        /// assert_eq!(Some("Return"), Scheme::X11.names()[13]);
+        /// assert_eq!(None, Scheme::Unicode.names()[13]);
+        /// ```
+        pub const fn names(&self) -> &'static [Option<&'static str>; 128] {
+            match self {
+                | Scheme::Unicode => &UNICODE,
+                | Scheme::Iso6429 => &ISO_6429,
+                | Scheme::Rfc1345 => &RFC_1345,
+                | Scheme::Adobe => &ADOBE,
+                | Scheme::X11 => &X11,
+            }
+        }
+    }
+
+    /// Unicode character name, printable codes only
+    pub static UNICODE: [Option<&str>; 128] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("SPACE"),
+        Some("EXCLAMATION MARK"),
+        Some("QUOTATION MARK"),
+        Some("NUMBER SIGN"),
+        Some("DOLLAR SIGN"),
+        Some("PERCENT SIGN"),
+        Some("AMPERSAND"),
+        Some("APOSTROPHE"),
+        Some("LEFT PARENTHESIS"),
+        Some("RIGHT PARENTHESIS"),
+        Some("ASTERISK"),
+        Some("PLUS SIGN"),
+        Some("COMMA"),
+        Some("HYPHEN-MINUS"),
+        Some("FULL STOP"),
+        Some("SOLIDUS"),
+        Some("DIGIT ZERO"),
+        Some("DIGIT ONE"),
+        Some("DIGIT TWO"),
+        Some("DIGIT THREE"),
+        Some("DIGIT FOUR"),
+        Some("DIGIT FIVE"),
+        Some("DIGIT SIX"),
+        Some("DIGIT SEVEN"),
+        Some("DIGIT EIGHT"),
+        Some("DIGIT NINE"),
+        Some("COLON"),
+        Some("SEMICOLON"),
+        Some("LESS-THAN SIGN"),
+        Some("EQUALS SIGN"),
+        Some("GREATER-THAN SIGN"),
+        Some("QUESTION MARK"),
+        Some("COMMERCIAL AT"),
+        Some("LATIN CAPITAL LETTER A"),
+        Some("LATIN CAPITAL LETTER B"),
+        Some("LATIN CAPITAL LETTER C"),
+        Some("LATIN CAPITAL LETTER D"),
+        Some("LATIN CAPITAL LETTER E"),
+        Some("LATIN CAPITAL LETTER F"),
+        Some("LATIN CAPITAL LETTER G"),
+        Some("LATIN CAPITAL LETTER H"),
+        Some("LATIN CAPITAL LETTER I"),
+        Some("LATIN CAPITAL LETTER J"),
+        Some("LATIN CAPITAL LETTER K"),
+        Some("LATIN CAPITAL LETTER L"),
+        Some("LATIN CAPITAL LETTER M"),
+        Some("LATIN CAPITAL LETTER N"),
+        Some("LATIN CAPITAL LETTER O"),
+        Some("LATIN CAPITAL LETTER P"),
+        Some("LATIN CAPITAL LETTER Q"),
+        Some("LATIN CAPITAL LETTER R"),
+        Some("LATIN CAPITAL LETTER S"),
+        Some("LATIN CAPITAL LETTER T"),
+        Some("LATIN CAPITAL LETTER U"),
+        Some("LATIN CAPITAL LETTER V"),
+        Some("LATIN CAPITAL LETTER W"),
+        Some("LATIN CAPITAL LETTER X"),
+        Some("LATIN CAPITAL LETTER Y"),
+        Some("LATIN CAPITAL LETTER Z"),
+        Some("LEFT SQUARE BRACKET"),
+        Some("REVERSE SOLIDUS"),
+        Some("RIGHT SQUARE BRACKET"),
+        Some("CIRCUMFLEX ACCENT"),
+        Some("LOW LINE"),
+        Some("GRAVE ACCENT"),
+        Some("LATIN SMALL LETTER A"),
+        Some("LATIN SMALL LETTER B"),
+        Some("LATIN SMALL LETTER C"),
+        Some("LATIN SMALL LETTER D"),
+        Some("LATIN SMALL LETTER E"),
+        Some("LATIN SMALL LETTER F"),
+        Some("LATIN SMALL LETTER G"),
+        Some("LATIN SMALL LETTER H"),
+        Some("LATIN SMALL LETTER I"),
+        Some("LATIN SMALL LETTER J"),
+        Some("LATIN SMALL LETTER K"),
+        Some("LATIN SMALL LETTER L"),
+        Some("LATIN SMALL LETTER M"),
+        Some("LATIN SMALL LETTER N"),
+        Some("LATIN SMALL LETTER O"),
+        Some("LATIN SMALL LETTER P"),
+        Some("LATIN SMALL LETTER Q"),
+        Some("LATIN SMALL LETTER R"),
+        Some("LATIN SMALL LETTER S"),
+        Some("LATIN SMALL LETTER T"),
+        Some("LATIN SMALL LETTER U"),
+        Some("LATIN SMALL LETTER V"),
+        Some("LATIN SMALL LETTER W"),
+        Some("LATIN SMALL LETTER X"),
+        Some("LATIN SMALL LETTER Y"),
+        Some("LATIN SMALL LETTER Z"),
+        Some("LEFT CURLY BRACKET"),
+        Some("VERTICAL LINE"),
+        Some("RIGHT CURLY BRACKET"),
+        Some("TILDE"),
+        None,
+    ];
+
+    /// ISO 6429 control function name, also Unicode 1.0 name, control codes only
+    pub static ISO_6429: [Option<&str>; 128] = [
+        Some("NULL"),
+        Some("START OF HEADING"),
+        Some("START OF TEXT"),
+        Some("END OF TEXT"),
+        Some("END OF TRANSMISSION"),
+        Some("ENQUIRY"),
+        Some("ACKNOWLEDGE"),
+        Some("BELL"),
+        Some("BACKSPACE"),
+        Some("CHARACTER TABULATION"),
+        Some("LINE FEED (LF)"),
+        Some("LINE TABULATION"),
+        Some("FORM FEED (FF)"),
+        Some("CARRIAGE RETURN (CR)"),
+        Some("SHIFT OUT"),
+        Some("SHIFT IN"),
+        Some("DATA LINK ESCAPE"),
+        Some("DEVICE CONTROL ONE"),
+        Some("DEVICE CONTROL TWO"),
+        Some("DEVICE CONTROL THREE"),
+        Some("DEVICE CONTROL FOUR"),
+        Some("NEGATIVE ACKNOWLEDGE"),
+        Some("SYNCHRONOUS IDLE"),
+        Some("END OF TRANSMISSION BLOCK"),
+        Some("CANCEL"),
+        Some("END OF MEDIUM"),
+        Some("SUBSTITUTE"),
+        Some("ESCAPE"),
+        Some("INFORMATION SEPARATOR FOUR"),
+        Some("INFORMATION SEPARATOR THREE"),
+        Some("INFORMATION SEPARATOR TWO"),
+        Some("INFORMATION SEPARATOR ONE"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("DELETE"),
+    ];
+
+    /// RFC 1345 mnemonic
+    pub static RFC_1345: [Option<&str>; 128] = [
+        Some("NU"),
+        Some("SH"),
+        Some("SX"),
+        Some("EX"),
+        Some("ET"),
+        Some("EQ"),
+        Some("AK"),
+        Some("BL"),
+        Some("BS"),
+        Some("HT"),
+        Some("LF"),
+        Some("VT"),
+        Some("FF"),
+        Some("CR"),
+        Some("SO"),
+        Some("SI"),
+        Some("DL"),
+        Some("D1"),
+        Some("D2"),
+        Some("D3"),
+        Some("D4"),
+        Some("NK"),
+        Some("SY"),
+        Some("EB"),
+        Some("CN"),
+        Some("EM"),
+        Some("SB"),
+        Some("EC"),
+        Some("FS"),
+        Some("GS"),
+        Some("RS"),
+        Some("US"),
+        Some("SP"),
+        Some("!"),
+        Some("\""),
+        Some("Nb"),
+        Some("DO"),
+        Some("%"),
+        Some("&"),
+        Some("'"),
+        Some("("),
+        Some(")"),
+        Some("*"),
+        Some("+"),
+        Some(","),
+        Some("-"),
+        Some("."),
+        Some("/"),
+        Some("0"),
+        Some("1"),
+        Some("2"),
+        Some("3"),
+        Some("4"),
+        Some("5"),
+        Some("6"),
+        Some("7"),
+        Some("8"),
+        Some("9"),
+        Some(":"),
+        Some(";"),
+        Some("<"),
+        Some("="),
+        Some(">"),
+        Some("?"),
+        Some("At"),
+        Some("A"),
+        Some("B"),
+        Some("C"),
+        Some("D"),
+        Some("E"),
+        Some("F"),
+        Some("G"),
+        Some("H"),
+        Some("I"),
+        Some("J"),
+        Some("K"),
+        Some("L"),
+        Some("M"),
+        Some("N"),
+        Some("O"),
+        Some("P"),
+        Some("Q"),
+        Some("R"),
+        Some("S"),
+        Some("T"),
+        Some("U"),
+        Some("V"),
+        Some("W"),
+        Some("X"),
+        Some("Y"),
+        Some("Z"),
+        Some("<("),
+        Some("//"),
+        Some(")>"),
+        Some("'>"),
+        Some("_"),
+        Some("'!"),
+        Some("a"),
+        Some("b"),
+        Some("c"),
+        Some("d"),
+        Some("e"),
+        Some("f"),
+        Some("g"),
+        Some("h"),
+        Some("i"),
+        Some("j"),
+        Some("k"),
+        Some("l"),
+        Some("m"),
+        Some("n"),
+        Some("o"),
+        Some("p"),
+        Some("q"),
+        Some("r"),
+        Some("s"),
+        Some("t"),
+        Some("u"),
+        Some("v"),
+        Some("w"),
+        Some("x"),
+        Some("y"),
+        Some("z"),
+        Some("(!"),
+        Some("!!"),
+        Some("!)"),
+        Some("'?"),
+        Some("DT"),
+    ];
+
+    /// Adobe Glyph List name, all codes except NUL
+    pub static ADOBE: [Option<&str>; 128] = [
+        None,
+        Some("controlSTX"),
+        Some("controlSOT"),
+        Some("controlETX"),
+        Some("controlEOT"),
+        Some("controlENQ"),
+        Some("controlACK"),
+        Some("controlBEL"),
+        Some("controlBS"),
+        Some("controlHT"),
+        Some("controlLF"),
+        Some("controlVT"),
+        Some("controlFF"),
+        Some("controlCR"),
+        Some("controlSO"),
+        Some("controlSI"),
+        Some("controlDLE"),
+        Some("controlDC1"),
+        Some("controlDC2"),
+        Some("controlDC3"),
+        Some("controlDC4"),
+        Some("controlNAK"),
+        Some("controlSYN"),
+        Some("controlETB"),
+        Some("controlCAN"),
+        Some("controlEM"),
+        Some("controlSUB"),
+        Some("controlESC"),
+        Some("controlFS"),
+        Some("controlGS"),
+        Some("controlRS"),
+        Some("controlUS"),
+        Some("space"),
+        Some("exclam"),
+        Some("quotedbl"),
+        Some("numbersign"),
+        Some("dollar"),
+        Some("percent"),
+        Some("ampersand"),
+        Some("quotesingle"),
+        Some("parenleft"),
+        Some("parenright"),
+        Some("asterisk"),
+        Some("plus"),
+        Some("comma"),
+        Some("hyphen"),
+        Some("period"),
+        Some("slash"),
+        Some("zero"),
+        Some("one"),
+        Some("two"),
+        Some("three"),
+        Some("four"),
+        Some("five"),
+        Some("six"),
+        Some("seven"),
+        Some("eight"),
+        Some("nine"),
+        Some("colon"),
+        Some("semicolon"),
+        Some("less"),
+        Some("equal"),
+        Some("greater"),
+        Some("question"),
+        Some("at"),
+        Some("A"),
+        Some("B"),
+        Some("C"),
+        Some("D"),
+        Some("E"),
+        Some("F"),
+        Some("G"),
+        Some("H"),
+        Some("I"),
+        Some("J"),
+        Some("K"),
+        Some("L"),
+        Some("M"),
+        Some("N"),
+        Some("O"),
+        Some("P"),
+        Some("Q"),
+        Some("R"),
+        Some("S"),
+        Some("T"),
+        Some("U"),
+        Some("V"),
+        Some("W"),
+        Some("X"),
+        Some("Y"),
+        Some("Z"),
+        Some("bracketleft"),
+        Some("backslash"),
+        Some("bracketright"),
+        Some("asciicircum"),
+        Some("underscore"),
+        Some("grave"),
+        Some("a"),
+        Some("b"),
+        Some("c"),
+        Some("d"),
+        Some("e"),
+        Some("f"),
+        Some("g"),
+        Some("h"),
+        Some("i"),
+        Some("j"),
+        Some("k"),
+        Some("l"),
+        Some("m"),
+        Some("n"),
+        Some("o"),
+        Some("p"),
+        Some("q"),
+        Some("r"),
+        Some("s"),
+        Some("t"),
+        Some("u"),
+        Some("v"),
+        Some("w"),
+        Some("x"),
+        Some("y"),
+        Some("z"),
+        Some("braceleft"),
+        Some("bar"),
+        Some("braceright"),
+        Some("asciitilde"),
+        Some("controlDEL"),
+    ];
+
+    /// X11 keysym name, printable codes and TTY function keys only
+    pub static X11: [Option<&str>; 128] = [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("BackSpace"),
+        Some("Tab"),
+        Some("Linefeed"),
+        Some("Clear"),
+        None,
+        Some("Return"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("Pause"),
+        Some("Scroll_Lock"),
+        Some("Sys_Req"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some("Escape"),
+        None,
+        None,
+        None,
+        None,
+        Some("space"),
+        Some("exclam"),
+        Some("quotedbl"),
+        Some("numbersign"),
+        Some("dollar"),
+        Some("percent"),
+        Some("ampersand"),
+        Some("apostrophe"),
+        Some("parenleft"),
+        Some("parenright"),
+        Some("asterisk"),
+        Some("plus"),
+        Some("comma"),
+        Some("minus"),
+        Some("period"),
+        Some("slash"),
+        Some("0"),
+        Some("1"),
+        Some("2"),
+        Some("3"),
+        Some("4"),
+        Some("5"),
+        Some("6"),
+        Some("7"),
+        Some("8"),
+        Some("9"),
+        Some("colon"),
+        Some("semicolon"),
+        Some("less"),
+        Some("equal"),
+        Some("greater"),
+        Some("question"),
+        Some("at"),
+        Some("A"),
+        Some("B"),
+        Some("C"),
+        Some("D"),
+        Some("E"),
+        Some("F"),
+        Some("G"),
+        Some("H"),
+        Some("I"),
+        Some("J"),
+        Some("K"),
+        Some("L"),
+        Some("M"),
+        Some("N"),
+        Some("O"),
+        Some("P"),
+        Some("Q"),
+        Some("R"),
+        Some("S"),
+        Some("T"),
+        Some("U"),
+        Some("V"),
+        Some("W"),
+        Some("X"),
+        Some("Y"),
+        Some("Z"),
+        Some("bracketleft"),
+        Some("backslash"),
+        Some("bracketright"),
+        Some("asciicircum"),
+        Some("underscore"),
+        Some("grave"),
+        Some("a"),
+        Some("b"),
+        Some("c"),
+        Some("d"),
+        Some("e"),
+        Some("f"),
+        Some("g"),
+        Some("h"),
+        Some("i"),
+        Some("j"),
+        Some("k"),
+        Some("l"),
+        Some("m"),
+        Some("n"),
+        Some("o"),
+        Some("p"),
+        Some("q"),
+        Some("r"),
+        Some("s"),
+        Some("t"),
+        Some("u"),
+        Some("v"),
+        Some("w"),
+        Some("x"),
+        Some("y"),
+        Some("z"),
+        Some("braceleft"),
+        Some("bar"),
+        Some("braceright"),
+        Some("asciitilde"),
+        Some("Delete"),
+    ];
+
+    #[cfg(test)]
+    mod tests_of_units {
+
+        use super::*;
+
+        #[test]
+        fn unicode() {
+            for (ix, n) in UNICODE.iter().enumerate() {
+                assert_eq!(ix > 31 && ix < 127, n.is_some());
+            }
+
+            assert_eq!(Some("DIGIT SEVEN"), UNICODE[b'7' as usize]);
+            assert_eq!(Some("LATIN SMALL LETTER Z"), UNICODE[b'z' as usize]);
+            assert_eq!(Some("HYPHEN-MINUS"), UNICODE[b'-' as usize]);
+        }
+
+        #[test]
+        fn iso_6429() {
+            for (ix, n) in ISO_6429.iter().enumerate() {
+                assert_eq!(ix < 32 || ix == 127, n.is_some());
+            }
+
+            assert_eq!(Some("INFORMATION SEPARATOR ONE"), ISO_6429[31]);
+            assert_eq!(Some("DELETE"), ISO_6429[127]);
+        }
+
+        #[test]
+        fn rfc_1345() {
+            assert!(RFC_1345.iter().all(|x| x.is_some_and(|x| x.len() <= 2)));
+            assert_eq!(Some("NU"), RFC_1345[0]);
+            assert_eq!(Some("Nb"), RFC_1345[b'#' as usize]);
+            assert_eq!(Some("A"), RFC_1345[b'A' as usize]);
+        }
+
+        #[test]
+        fn adobe() {
+            for (ix, n) in ADOBE.iter().enumerate() {
+                assert_eq!(ix != 0, n.is_some());
+            }
+
+            assert_eq!(Some("controlSTX"), ADOBE[1]);
+            assert_eq!(Some("controlSOT"), ADOBE[2]);
+            assert_eq!(Some("five"), ADOBE[b'5' as usize]);
+        }
+
+        #[test]
+        fn x11() {
+            let controls = X11[..32].iter().chain(X11[127..].iter());
+            assert_eq!(10, controls.flatten().count());
+            assert!(X11[32..127].iter().all(|x| x.is_some()));
+
+            assert_eq!(Some("Escape"), X11[27]);
+            assert_eq!(Some("Delete"), X11[127]);
+            assert_eq!(Some("apostrophe"), X11[b'\'' as usize]);
+        }
+
+        #[test]
+        fn names() {
+            for s in Scheme::ALL.iter() {
+                assert_eq!(s.names()[b'A' as usize].is_some(), *s != Scheme::Iso6429);
+            }
+        }
+    }
 }
@@ -0,0 +1,209 @@
+//! Per-byte annotation of arbitrary input.
+
+use std::io::{BufReader, Bytes, Read};
+use std::iter::FusedIterator;
+use huski_lib_core::code::{Code, CODES};
+use huski_lib_core::ranges::Ranges;
+use crate::order::class;
+
+/// Describes single byte of input.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Annotation {
+    offset: usize,
+    byte: u8,
+    line: usize,
+    column: usize,
+}
+
+impl Annotation {
+    /// Zero-based offset of byte within input.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Byte value.
+    pub const fn byte(&self) -> u8 {
+        self.byte
+    }
+
+    /// `Code` of byte, `None` for bytes out of table range 0-127.
+    pub fn code(&self) -> Option<&'static Code> {
+        CODES.get(self.byte as usize)
+    }
+
+    /// One-based line, line feed is last byte of its line.
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// One-based column, counted in bytes.
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Class of byte, see `order::CLASSES`, `None` for bytes out of table range 0-127.
+    pub fn class(&self) -> Option<Ranges> {
+        self.code().map(class)
+    }
+}
+
+/// Line and column tracking.
+#[derive(Clone)]
+struct Position {
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Position {
+    const fn new() -> Self {
+        Position {
+            offset: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn annotate(&mut self, byte: u8) -> Annotation {
+        let a = Annotation {
+            offset: self.offset,
+            byte,
+            line: self.line,
+            column: self.column,
+        };
+
+        self.offset += 1;
+        if byte == b'\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+
+        a
+    }
+}
+
+/// Annotates each byte of `bs`.
+/// ```
+/// use huski_lib::{annotate::annotate, Ranges};
+///
+/// let a = annotate(b"a\n\xff").collect::<Vec<_>>();
+///
+/// assert_eq!("LF", a[1].code().unwrap().human());
+/// assert_eq!(Some(Ranges::Small), a[0].class());
+/// assert_eq!((2, 1), (a[2].line(), a[2].column()));
+/// assert_eq!(None, a[2].code());
+/// ```
+pub fn annotate(bs: &[u8]) -> Annotate<'_> {
+    Annotate {
+        bs: bs.iter(),
+        position: Position::new(),
+    }
+}
+
+/// Annotates each byte read from `r`. Reading is buffered.
+pub fn annotate_read<R: Read>(r: R) -> AnnotateRead<R> {
+    AnnotateRead {
+        bytes: BufReader::new(r).bytes(),
+        position: Position::new(),
+    }
+}
+
+/// Iterator over annotations of byte slice.
+#[derive(Clone)]
+pub struct Annotate<'a> {
+    bs: core::slice::Iter<'a, u8>,
+    position: Position,
+}
+
+impl Iterator for Annotate<'_> {
+    type Item = Annotation;
+
+    fn next(&mut self) -> Option<Annotation> {
+        let &b = self.bs.next()?;
+        Some(self.position.annotate(b))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.bs.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Annotate<'_> {}
+
+impl FusedIterator for Annotate<'_> {}
+
+/// Iterator over annotations of bytes read. Read errors are yielded as they occur.
+pub struct AnnotateRead<R> {
+    bytes: Bytes<BufReader<R>>,
+    position: Position,
+}
+
+impl<R: Read> Iterator for AnnotateRead<R> {
+    type Item = std::io::Result<Annotation>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.bytes.next()? {
+            | Ok(b) => Some(Ok(self.position.annotate(b))),
+            | Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_of_units {
+
+    use super::*;
+
+    #[test]
+    fn basic_test() {
+        let test = annotate(b"A1\r\n~\x00\x80").collect::<Vec<Annotation>>();
+
+        assert_eq!(7, test.len());
+
+        let proof = [
+            (0, b'A', 1, 1, Some(Ranges::Capital)),
+            (1, b'1', 1, 2, Some(Ranges::Digits)),
+            (2, b'\r', 1, 3, Some(Ranges::Control)),
+            (3, b'\n', 1, 4, Some(Ranges::Control)),
+            (4, b'~', 2, 1, Some(Ranges::Symbols)),
+            (5, 0, 2, 2, Some(Ranges::Control)),
+            (6, 0x80, 2, 3, None),
+        ];
+
+        for (a, p) in test.iter().zip(proof.iter()) {
+            assert_eq!(p.0, a.offset());
+            assert_eq!(p.1, a.byte());
+            assert_eq!(p.2, a.line());
+            assert_eq!(p.3, a.column());
+            assert_eq!(p.4, a.class());
+            assert_eq!(p.1 < 128, a.code().is_some());
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(0, annotate(b"").count());
+        assert_eq!(0, annotate_read(&b""[..]).count());
+    }
+
+    #[test]
+    fn read() {
+        let bs = b"ab\ncd\n\n";
+        let proof = annotate(bs).collect::<Vec<Annotation>>();
+        let test = annotate_read(&bs[..])
+            .collect::<Result<Vec<Annotation>, _>>()
+            .unwrap();
+
+        assert_eq!(proof, test);
+        assert_eq!(3, test[6].line());
+    }
+
+    #[test]
+    fn size_hint() {
+        let mut test = annotate(b"abc");
+        _ = test.next();
+        assert_eq!(2, test.len());
+    }
+}
@@ -0,0 +1,75 @@
+//! Number bases of numeric representation.
+
+/// Number base, discriminant is radix.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Base {
+    Binary = 2,
+    Octal = 8,
+    Decimal = 10,
+    Hexadecimal = 16,
+}
+
+impl Base {
+    /// All variants.
+    pub const ALL: [Base; 4] = [Base::Binary, Base::Octal, Base::Decimal, Base::Hexadecimal];
+
+    /// Radix.
+    pub const fn radix(&self) -> u32 {
+        match self {
+            | Base::Binary => 2,
+            | Base::Octal => 8,
+            | Base::Decimal => 10,
+            | Base::Hexadecimal => 16,
+        }
+    }
+
+    /// Number of digits needed for any byte value.
+    pub const fn digits(&self) -> usize {
+        match self {
+            | Base::Binary => 8,
+            | Base::Octal => 3,
+            | Base::Decimal => 3,
+            | Base::Hexadecimal => 2,
+        }
+    }
+
+    /// Formats `b` zero-padded to `fn digits()`, without prefix.
+    /// ```
+    /// use huski_lib::Base;
+    ///
+    /// assert_eq!("00001010", Base::Binary.format(10));
+    /// assert_eq!("0a", Base::Hexadecimal.format(10));
+    /// ```
+    pub fn format(&self, b: u8) -> String {
+        match self {
+            | Base::Binary => format!("{:08b}", b),
+            | Base::Octal => format!("{:03o}", b),
+            | Base::Decimal => format!("{:03}", b),
+            | Base::Hexadecimal => format!("{:02x}", b),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_of_units {
+
+    use super::Base;
+
+    #[test]
+    fn radix() {
+        for b in Base::ALL {
+            assert_eq!(b.clone() as u32, b.radix());
+        }
+    }
+
+    #[test]
+    fn format() {
+        for b in Base::ALL {
+            for x in [0, 7, 127, 255] {
+                let test = b.format(x);
+                assert_eq!(b.digits(), test.len());
+                assert_eq!(Ok(x), u8::from_str_radix(test.as_str(), b.radix()));
+            }
+        }
+    }
+}
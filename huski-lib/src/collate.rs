@@ -0,0 +1,258 @@
+//! Collation orders of ASCII strings.
+//!
+//! Each `Collation` provides both comparator, `fn compare()`, and sort key,
+//! `fn key()`, ordering the same way.
+
+use core::cmp::Ordering;
+use huski_lib_core::case::fold;
+use huski_lib_core::class::{is_alphanumeric, is_blank, is_digit};
+
+/// Collation orders.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Collation {
+    /// Code value order, bytewise
+    Code,
+    /// Code value order after case folding
+    NoCase,
+    /// Only alphanumeric and blank codes considered, after case folding
+    Dictionary,
+    /// Digit runs compared by numeric value, otherwise code value order
+    Natural,
+}
+
+impl Collation {
+    /// All variants.
+    pub const ALL: [Collation; 4] = [
+        Collation::Code,
+        Collation::NoCase,
+        Collation::Dictionary,
+        Collation::Natural,
+    ];
+
+    /// Name, suitable for identification.
+    /// ```
+    /// use huski_lib::collate::Collation;
+    ///
+    /// assert_eq!("nocase", Collation::NoCase.name());
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        match self {
+            | Collation::Code => "code",
+            | Collation::NoCase => "nocase",
+            | Collation::Dictionary => "dictionary",
+            | Collation::Natural => "natural",
+        }
+    }
+}
+
+impl core::fmt::Display for Collation {
+    /// Writes `fn name()`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl core::str::FromStr for Collation {
+    type Err = ParseCollationError;
+
+    /// Parses `fn name()`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Collation::ALL
+            .iter()
+            .find(|x| x.name() == s)
+            .cloned()
+            .ok_or(ParseCollationError)
+    }
+}
+
+/// Input is not name of any `Collation`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseCollationError;
+
+impl core::fmt::Display for ParseCollationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("unknown collation name")
+    }
+}
+
+impl std::error::Error for ParseCollationError {}
+
+/// Compares `a` and `b` by `c`.
+/// ```
+/// use core::cmp::Ordering;
+/// use huski_lib::collate::{compare, Collation};
+///
+/// assert_eq!(Ordering::Less, compare(b"B", b"a", Collation::Code));
+/// assert_eq!(Ordering::Greater, compare(b"B", b"a", Collation::NoCase));
+/// assert_eq!(Ordering::Equal, compare(b"re-do", b"redo", Collation::Dictionary));
+/// assert_eq!(Ordering::Less, compare(b"file9", b"file10", Collation::Natural));
+/// ```
+pub fn compare(a: &[u8], b: &[u8], c: Collation) -> Ordering {
+    match c {
+        | Collation::Code => a.cmp(b),
+        | Collation::NoCase => a.iter().map(|&x| fold(x)).cmp(b.iter().map(|&x| fold(x))),
+        | Collation::Dictionary => dictionary(a).cmp(dictionary(b)),
+        | Collation::Natural => natural(a, b),
+    }
+}
+
+/// Provides sort key of `s` by `c`.
+///
+/// Keys order bytewise same as `fn compare()` orders inputs.
+/// ```
+/// use huski_lib::collate::{key, Collation};
+///
+/// let mut names = vec!["img12", "img2", "img1"];
+/// names.sort_by_key(|x| key(x.as_bytes(), Collation::Natural));
+///
+/// assert_eq!(vec!["img1", "img2", "img12"], names);
+/// ```
+pub fn key(s: &[u8], c: Collation) -> Vec<u8> {
+    match c {
+        | Collation::Code => s.to_vec(),
+        | Collation::NoCase => s.iter().map(|&x| fold(x)).collect(),
+        | Collation::Dictionary => dictionary(s).collect(),
+        | Collation::Natural => natural_key(s),
+    }
+}
+
+fn dictionary(s: &[u8]) -> impl Iterator<Item = u8> + '_ {
+    s.iter()
+        .filter(|&&x| is_alphanumeric(x) || is_blank(x))
+        .map(|&x| fold(x))
+}
+
+/// Digit run starting at `start` with leading zeros trimmed, and its end.
+fn digit_run(s: &[u8], start: usize) -> (&[u8], usize) {
+    let mut end = start;
+    while end < s.len() && is_digit(s[end]) {
+        end += 1;
+    }
+
+    let mut trim = start;
+    while trim < end && s[trim] == b'0' {
+        trim += 1;
+    }
+
+    (&s[trim..end], end)
+}
+
+fn natural(a: &[u8], b: &[u8]) -> Ordering {
+    let (mut aix, mut bix) = (0, 0);
+    while aix < a.len() && bix < b.len() {
+        if is_digit(a[aix]) && is_digit(b[bix]) {
+            let (a_run, a_end) = digit_run(a, aix);
+            let (b_run, b_end) = digit_run(b, bix);
+
+            let ord = a_run.len().cmp(&b_run.len()).then_with(|| a_run.cmp(b_run));
+            if ord != Ordering::Equal {
+                return ord;
+            }
+
+            (aix, bix) = (a_end, b_end);
+        } else {
+            let ord = a[aix].cmp(&b[bix]);
+            if ord != Ordering::Equal {
+                return ord;
+            }
+
+            (aix, bix) = (aix + 1, bix + 1);
+        }
+    }
+
+    (a.len() - aix).cmp(&(b.len() - bix))
+}
+
+/// Digit run is encoded as `0`, its trimmed length as 4 bytes big-endian and its
+/// trimmed digits, thus it orders against other codes as any digit does.
+fn natural_key(s: &[u8]) -> Vec<u8> {
+    let mut key = Vec::with_capacity(s.len());
+
+    let mut ix = 0;
+    while ix < s.len() {
+        if is_digit(s[ix]) {
+            let (run, end) = digit_run(s, ix);
+            key.push(b'0');
+            key.extend_from_slice(&(run.len() as u32).to_be_bytes());
+            key.extend_from_slice(run);
+            ix = end;
+        } else {
+            key.push(s[ix]);
+            ix += 1;
+        }
+    }
+
+    key
+}
+
+#[cfg(test)]
+mod tests_of_units {
+
+    use core::cmp::Ordering;
+    use super::*;
+
+    static SAMPLES: [&[u8]; 16] = [
+        b"", b"a", b"A", b"a1", b"a01", b"a2", b"a10", b"a10b", b"a-b", b"ab", b"a b", b"A_1",
+        b"z9", b"_", b"007", b"7",
+    ];
+
+    #[test]
+    fn key_consistency() {
+        for c in Collation::ALL {
+            for a in SAMPLES.iter() {
+                for b in SAMPLES.iter() {
+                    let proof = compare(a, b, c);
+                    let test = key(a, c).cmp(&key(b, c));
+                    assert_eq!(proof, test, "{:?} {:?} {:?}", c, a, b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn code() {
+        assert_eq!(Ordering::Less, compare(b"Z", b"a", Collation::Code));
+        assert_eq!(Ordering::Less, compare(b"a10", b"a2", Collation::Code));
+    }
+
+    #[test]
+    fn no_case() {
+        assert_eq!(Ordering::Greater, compare(b"Z", b"a", Collation::NoCase));
+        assert_eq!(Ordering::Equal, compare(b"ABC", b"abc", Collation::NoCase));
+    }
+
+    #[test]
+    fn dictionary() {
+        let c = Collation::Dictionary;
+        assert_eq!(Ordering::Equal, compare(b"a-b", b"ab", c));
+        assert_eq!(Ordering::Less, compare(b"a b", b"ab", c));
+        assert_eq!(Ordering::Equal, compare(b"_", b"", c));
+        assert_eq!(Ordering::Less, compare(b"A_1", b"a2", c));
+    }
+
+    #[test]
+    fn natural() {
+        let c = Collation::Natural;
+        assert_eq!(Ordering::Less, compare(b"a2", b"a10", c));
+        assert_eq!(Ordering::Equal, compare(b"a01", b"a1", c));
+        assert_eq!(Ordering::Equal, compare(b"007", b"7", c));
+        assert_eq!(Ordering::Less, compare(b"a10", b"a10b", c));
+        assert_eq!(Ordering::Less, compare(b"a10", b"ab", c));
+        assert_eq!(Ordering::Less, compare(b"A", b"a", c));
+
+        let long = [b'9'; 300];
+        let longer = [b'1'; 301];
+        assert_eq!(Ordering::Less, compare(&long, &longer, c));
+        assert_eq!(Ordering::Less, key(&long, c).cmp(&key(&longer, c)));
+    }
+
+    #[test]
+    fn from_str() {
+        for c in Collation::ALL {
+            assert_eq!(Ok(c), c.name().parse());
+            assert_eq!(c.name(), c.to_string());
+        }
+
+        assert_eq!(Err(ParseCollationError), "Code".parse::<Collation>());
+    }
+}
@@ -0,0 +1,458 @@
+//! Line by line difference of two inputs, aimed at invisible codes.
+//!
+//! Lines are aligned by their visible content, bytes of graphic codes, thus
+//! lines differing only in invisible codes pair up. Within each pair of lines
+//! common prefix and common suffix are stripped, remaining spans are
+//! difference. Thus CR LF versus LF, tab versus spaces, trailing space or
+//! no-break space show as short spans. Lines left unaligned between aligned
+//! ones pair in order, rest of them is whole line added or removed.
+
+use huski_lib_core::class::is_graphic;
+use huski_lib_core::code::CODES;
+
+/// Kind of difference.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Change {
+    /// Line is in both inputs, differing span of it
+    Modified,
+    /// Line is only in right input, whole line
+    Added,
+    /// Line is only in left input, whole line
+    Removed,
+}
+
+/// Differing span of line pair, or whole line missing in one input.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Difference<'a> {
+    change: Change,
+    lines: (usize, usize),
+    column: usize,
+    offsets: (usize, usize),
+    left: &'a [u8],
+    right: &'a [u8],
+}
+
+impl<'a> Difference<'a> {
+    /// Kind of difference.
+    pub const fn change(&self) -> &Change {
+        &self.change
+    }
+
+    /// One-based lines within left and right input, line feed is last byte of
+    /// its line. Line missing in one input has there number of line it precedes.
+    pub const fn lines(&self) -> (usize, usize) {
+        self.lines
+    }
+
+    /// One-based column of first differing byte, counted in bytes.
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Zero-based offsets of first differing byte within left and right input.
+    pub const fn offsets(&self) -> (usize, usize) {
+        self.offsets
+    }
+
+    /// Differing bytes of left input, empty if they are missing.
+    pub const fn left(&self) -> &'a [u8] {
+        self.left
+    }
+
+    /// Differing bytes of right input, empty if they are missing.
+    pub const fn right(&self) -> &'a [u8] {
+        self.right
+    }
+}
+
+/// Differences of `l` and `r`, in line order.
+///
+/// Lines are aligned by shortest edit script of Myers, thus inserted or
+/// deleted line is single difference. Cost grows with square of count of
+/// unaligned lines.
+/// ```
+/// use huski_lib::diff::{diff, Change};
+///
+/// let test = diff(b"a = 1\r\nb\t= 2\n", b"a = 1\nx\nb  = 2\n");
+///
+/// assert_eq!(3, test.len());
+/// assert_eq!((1, 1, 6), (test[0].lines().0, test[0].lines().1, test[0].column()));
+/// assert_eq!((b"\r".as_slice(), b"".as_slice()), (test[0].left(), test[0].right()));
+/// assert_eq!((&Change::Added, b"x\n".as_slice()), (test[1].change(), test[1].right()));
+/// assert_eq!((b"\t".as_slice(), b"  ".as_slice()), (test[2].left(), test[2].right()));
+/// assert_eq!((2, 3), test[2].lines());
+/// assert_eq!((8, 9), test[2].offsets());
+/// ```
+pub fn diff<'a>(l: &'a [u8], r: &'a [u8]) -> Vec<Difference<'a>> {
+    let ls = Lines::new(l);
+    let rs = Lines::new(r);
+
+    let mut ds = Vec::new();
+    let (mut lix, mut rix) = (0, 0);
+
+    let aligned = align(&ls.keys, &rs.keys);
+    let end = (ls.lines.len(), rs.lines.len());
+
+    for &(l_end, r_end) in aligned.iter().chain([end].iter()) {
+        // unaligned lines, paired in order
+        while lix < l_end && rix < r_end {
+            ds.push(modified(&ls, &rs, lix, rix));
+            lix += 1;
+            rix += 1;
+        }
+
+        while lix < l_end {
+            ds.push(Difference {
+                change: Change::Removed,
+                lines: (lix + 1, rix + 1),
+                column: 1,
+                offsets: (ls.offset(lix), rs.offset(rix)),
+                left: ls.lines[lix],
+                right: &[],
+            });
+
+            lix += 1;
+        }
+
+        while rix < r_end {
+            ds.push(Difference {
+                change: Change::Added,
+                lines: (lix + 1, rix + 1),
+                column: 1,
+                offsets: (ls.offset(lix), rs.offset(rix)),
+                left: &[],
+                right: rs.lines[rix],
+            });
+
+            rix += 1;
+        }
+
+        if (l_end, r_end) == end {
+            break;
+        }
+
+        if ls.lines[lix] != rs.lines[rix] {
+            ds.push(modified(&ls, &rs, lix, rix));
+        }
+
+        lix += 1;
+        rix += 1;
+    }
+
+    ds
+}
+
+/// Lines of input with their offsets and alignment keys.
+struct Lines<'a> {
+    lines: Vec<&'a [u8]>,
+    offsets: Vec<usize>,
+    keys: Vec<Vec<u8>>,
+}
+
+impl<'a> Lines<'a> {
+    fn new(bs: &'a [u8]) -> Self {
+        let lines = bs.split_inclusive(|&x| x == b'\n').collect::<Vec<&[u8]>>();
+
+        let mut offsets = Vec::with_capacity(lines.len() + 1);
+        let mut offset = 0;
+        for l in lines.iter() {
+            offsets.push(offset);
+            offset += l.len();
+        }
+
+        offsets.push(offset);
+
+        let keys = lines
+            .iter()
+            .map(|x| x.iter().copied().filter(|&b| is_graphic(b)).collect())
+            .collect();
+
+        Lines { lines, offsets, keys }
+    }
+
+    /// Offset of line `ix`, or of input end.
+    fn offset(&self, ix: usize) -> usize {
+        self.offsets[ix]
+    }
+}
+
+fn modified<'a>(ls: &Lines<'a>, rs: &Lines<'a>, lix: usize, rix: usize) -> Difference<'a> {
+    let (l, r) = (ls.lines[lix], rs.lines[rix]);
+
+    let prefix = l.iter().zip(r.iter()).take_while(|(x, y)| x == y).count();
+    let (l_rest, r_rest) = (&l[prefix..], &r[prefix..]);
+
+    let suffix = l_rest
+        .iter()
+        .rev()
+        .zip(r_rest.iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+
+    Difference {
+        change: Change::Modified,
+        lines: (lix + 1, rix + 1),
+        column: prefix + 1,
+        offsets: (ls.offset(lix) + prefix, rs.offset(rix) + prefix),
+        left: &l_rest[..l_rest.len() - suffix],
+        right: &r_rest[..r_rest.len() - suffix],
+    }
+}
+
+/// Aligned index pairs of `a` and `b`, ascending, by greedy algorithm of Myers.
+fn align<T: PartialEq>(a: &[T], b: &[T]) -> Vec<(usize, usize)> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max = n + m;
+
+    // furthest `x` on diagonal `k`, `k - 1` and `k + 1` must be addressable
+    let mut v = vec![0; 2 * max as usize + 3];
+    let vix = |k: isize| (k + max + 1) as usize;
+
+    // `v` of diagonals `-d..=d` after each `d` edits
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+    let mut edits = 0;
+
+    'edits: for d in 0..=max {
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && v[vix(k - 1)] < v[vix(k + 1)]) {
+                v[vix(k + 1)]
+            } else {
+                v[vix(k - 1)] + 1
+            };
+
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+
+            v[vix(k)] = x;
+            if x >= n && y >= m {
+                edits = d;
+                break 'edits;
+            }
+        }
+
+        trace.push(v[vix(-d)..=vix(d)].to_vec());
+    }
+
+    let mut aligned = Vec::new();
+    let (mut x, mut y) = (n, m);
+
+    for d in (1..=edits).rev() {
+        let prev = &trace[d as usize - 1];
+        let get = |k: isize| prev[(k + d - 1) as usize];
+
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && get(k - 1) < get(k + 1)) { k + 1 } else { k - 1 };
+
+        let prev_x = get(prev_k);
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            aligned.push((x as usize, y as usize));
+        }
+
+        x = prev_x;
+        y = prev_y;
+    }
+
+    while x > 0 && y > 0 {
+        x -= 1;
+        y -= 1;
+        aligned.push((x as usize, y as usize));
+    }
+
+    aligned.reverse();
+    aligned
+}
+
+/// Renders `bs` with control codes and space as mnemonic in angle brackets,
+/// bytes out of table range 0-127 as hexadecimal in angle brackets.
+/// ```
+/// use huski_lib::diff::mnemonics;
+///
+/// assert_eq!("a<SP><HT><CR><0xC2><0xA0>", mnemonics(b"a \t\r\xc2\xa0"));
+/// ```
+pub fn mnemonics(bs: &[u8]) -> String {
+    let mut o = String::with_capacity(bs.len() * 4);
+    for &b in bs {
+        match CODES.get(b as usize) {
+            | Some(c) if b == b' ' => {
+                o.push('<');
+                o.push_str(c.aliases()[0]);
+                o.push('>');
+            },
+            | Some(c) if b < 32 || b == 127 => {
+                o.push('<');
+                o.push_str(c.human());
+                o.push('>');
+            },
+            | Some(_) => o.push(b as char),
+            | None => o.push_str(format!("<0x{:02X}>", b).as_str()),
+        }
+    }
+
+    o
+}
+
+#[cfg(test)]
+mod tests_of_units {
+
+    use super::*;
+
+    mod diff {
+        use super::*;
+
+        #[test]
+        fn identical() {
+            assert_eq!(0, diff(b"a\r\nb\n", b"a\r\nb\n").len());
+            assert_eq!(0, diff(b"", b"").len());
+        }
+
+        #[test]
+        fn crlf() {
+            let test = diff(b"ab\r\ncd\r\n", b"ab\ncd\n");
+            assert_eq!(2, test.len());
+
+            let proof = [((1, 1), 3, (2, 2)), ((2, 2), 3, (6, 5))];
+            for (t, p) in test.iter().zip(proof.iter()) {
+                assert_eq!(&Change::Modified, t.change());
+                assert_eq!(p.0, t.lines());
+                assert_eq!(p.1, t.column());
+                assert_eq!(p.2, t.offsets());
+                assert_eq!(b"\r", t.left());
+                assert_eq!(b"", t.right());
+            }
+        }
+
+        #[test]
+        fn trailing_space() {
+            let test = diff(b"x \ny", b"x\ny");
+            assert_eq!(1, test.len());
+            assert_eq!(
+                (b" ".as_slice(), b"".as_slice()),
+                (test[0].left(), test[0].right())
+            );
+            assert_eq!(2, test[0].column());
+        }
+
+        #[test]
+        fn no_break_space() {
+            let test = diff("a\u{a0}b".as_bytes(), b"a b");
+            assert_eq!(1, test.len());
+            assert_eq!(b"\xc2\xa0", test[0].left());
+            assert_eq!(b" ", test[0].right());
+        }
+
+        #[test]
+        fn missing_lines() {
+            let test = diff(b"a\nb\nc\n", b"a\n");
+            assert_eq!(2, test.len());
+            assert_eq!(&Change::Removed, test[0].change());
+            assert_eq!(((2, 2), 1), (test[0].lines(), test[0].column()));
+            assert_eq!(b"b\n", test[0].left());
+            assert_eq!(b"", test[0].right());
+            assert_eq!((4, 2), test[1].offsets());
+            assert_eq!((3, 2), test[1].lines());
+
+            let test = diff(b"", b"z");
+            assert_eq!(&Change::Added, test[0].change());
+            assert_eq!(b"z", test[0].right());
+        }
+
+        #[test]
+        fn inserted_line() {
+            let test = diff(b"a\nb\nc\n", b"a\nx\nb\nc\n");
+            assert_eq!(1, test.len());
+            assert_eq!(&Change::Added, test[0].change());
+            assert_eq!(
+                (b"".as_slice(), b"x\n".as_slice()),
+                (test[0].left(), test[0].right())
+            );
+            assert_eq!(((2, 2), 1), (test[0].lines(), test[0].column()));
+            assert_eq!((2, 2), test[0].offsets());
+        }
+
+        #[test]
+        fn deleted_line() {
+            let test = diff(b"a\nx\nb\r\n", b"a\nb\n");
+            assert_eq!(2, test.len());
+            assert_eq!(&Change::Removed, test[0].change());
+            assert_eq!(b"x\n", test[0].left());
+
+            // later lines stay aligned
+            assert_eq!(&Change::Modified, test[1].change());
+            assert_eq!((3, 2), test[1].lines());
+            assert_eq!(
+                (b"\r".as_slice(), b"".as_slice()),
+                (test[1].left(), test[1].right())
+            );
+            assert_eq!((5, 3), test[1].offsets());
+        }
+
+        #[test]
+        fn unaligned_lines() {
+            let test = diff(b"a\nb\nc\nd\n", b"a\nx\ny\nz\nd\n");
+            assert_eq!(3, test.len());
+
+            let proof = [Change::Modified, Change::Modified, Change::Added];
+            for (t, p) in test.iter().zip(proof.iter()) {
+                assert_eq!(p, t.change());
+            }
+
+            assert_eq!(
+                (b"b".as_slice(), b"x".as_slice()),
+                (test[0].left(), test[0].right())
+            );
+            assert_eq!((3, 3), test[1].lines());
+            assert_eq!(
+                ((4, 4), b"z\n".as_slice()),
+                (test[2].lines(), test[2].right())
+            );
+        }
+
+        #[test]
+        fn overlapping_affixes() {
+            let test = diff(b"aa\n", b"aaa\n");
+            assert_eq!(1, test.len());
+            assert_eq!(b"", test[0].left());
+            assert_eq!(b"a", test[0].right());
+        }
+    }
+
+    mod align {
+        use super::*;
+
+        #[test]
+        fn basic_test() {
+            let test = align(b"abcabba", b"cbabac");
+            assert_eq!(4, test.len());
+            for &(x, y) in test.iter() {
+                assert_eq!(b"abcabba"[x], b"cbabac"[y]);
+            }
+
+            assert!(test.windows(2).all(|x| x[0].0 < x[1].0 && x[0].1 < x[1].1));
+        }
+
+        #[test]
+        fn edges() {
+            assert!(align::<u8>(b"", b"").is_empty());
+            assert!(align(b"", b"a").is_empty());
+            assert!(align(b"ab", b"").is_empty());
+            assert_eq!(vec![(0, 0), (1, 1)], align(b"ab", b"ab"));
+            assert_eq!(vec![(0, 1)], align(b"a", b"xa"));
+        }
+    }
+
+    mod mnemonics {
+        use super::*;
+
+        #[test]
+        fn basic_test() {
+            assert_eq!("<NUL>x<DEL><LF>", mnemonics(b"\x00x\x7f\n"));
+            assert_eq!("", mnemonics(b""));
+        }
+    }
+}
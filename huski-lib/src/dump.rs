@@ -0,0 +1,355 @@
+//! Hexdump with ASCII-aware gutter, and its parsing back.
+//!
+//! Line layout follows `xxd`: hexadecimal offset, colon, groups of bytes
+//! separated by space, two spaces, gutter.
+//! ```text
+//! 00000000: 4869 0d0a  Hi<CR><LF>
+//! ```
+
+use huski_lib_core::code::CODES;
+use crate::Base;
+
+/// Rendering of gutter, text representation of bytes.
+///
+/// Printable codes render as they are, bytes out of table range 0-127 as `.` in all styles.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Gutter {
+    /// Control codes as `.`, same as `xxd`
+    Dot,
+    /// Control codes as mnemonic in angle brackets, i.e. `<ESC>`
+    Mnemonic,
+    /// Control codes in caret notation, i.e. `^[`
+    Caret,
+    /// Control codes as Unicode Control Pictures glyph, i.e. `␛`
+    Picture,
+}
+
+impl Gutter {
+    /// All variants.
+    pub const ALL: [Gutter; 4] = [
+        Gutter::Dot,
+        Gutter::Mnemonic,
+        Gutter::Caret,
+        Gutter::Picture,
+    ];
+
+    /// Name, suitable for identification.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            | Gutter::Dot => "dot",
+            | Gutter::Mnemonic => "mnemonic",
+            | Gutter::Caret => "caret",
+            | Gutter::Picture => "picture",
+        }
+    }
+
+    /// Writes gutter representation of `b` into `o`.
+    fn render(&self, b: u8, o: &mut String) {
+        let Some(c) = CODES.get(b as usize) else {
+            o.push('.');
+            return;
+        };
+
+        if (32..127).contains(&b) {
+            o.push(b as char);
+            return;
+        }
+
+        match self {
+            | Gutter::Dot => o.push('.'),
+            | Gutter::Mnemonic => {
+                o.push('<');
+                o.push_str(c.human());
+                o.push('>');
+            },
+            | Gutter::Caret => o.push_str(c.caret().unwrap_or(".")),
+            | Gutter::Picture => o.push_str(c.picture().unwrap_or(".")),
+        }
+    }
+}
+
+/// Dump layout.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Layout {
+    /// Bytes per line
+    pub width: usize,
+    /// Bytes per group
+    pub group: usize,
+    /// Base of bytes
+    pub base: Base,
+    /// Gutter style
+    pub gutter: Gutter,
+}
+
+impl Default for Layout {
+    /// Same as `xxd` default, except mnemonic gutter.
+    fn default() -> Self {
+        Layout {
+            width: 16,
+            group: 2,
+            base: Base::Hexadecimal,
+            gutter: Gutter::Mnemonic,
+        }
+    }
+}
+
+/// Dumps `bs` by `l`.
+///
+/// Zero `l.width` or `l.group` is taken as 1.
+/// ```
+/// use huski_lib::dump::{dump, Layout};
+///
+/// let l = Layout {
+///     width: 4,
+///     ..Layout::default()
+/// };
+///
+/// assert_eq!("00000000: 4869 0d0a  Hi<CR><LF>\n", dump(b"Hi\r\n", &l));
+/// ```
+pub fn dump(bs: &[u8], l: &Layout) -> String {
+    let width = l.width.max(1);
+    let group = l.group.max(1);
+
+    let digits = l.base.digits();
+    let groups = width.div_ceil(group);
+    let area = width * digits + groups - 1;
+
+    let mut o = String::with_capacity(bs.len() / width * (area + width + 12) + 1);
+    for (lix, line) in bs.chunks(width).enumerate() {
+        o.push_str(format!("{:08x}: ", lix * width).as_str());
+        let start = o.len();
+
+        for (gix, g) in line.chunks(group).enumerate() {
+            if gix > 0 {
+                o.push(' ');
+            }
+
+            for &b in g {
+                o.push_str(l.base.format(b).as_str());
+            }
+        }
+
+        let written = o.len() - start;
+        for _ in written..area {
+            o.push(' ');
+        }
+
+        o.push_str("  ");
+        for &b in line {
+            l.gutter.render(b, &mut o);
+        }
+
+        o.push('\n');
+    }
+
+    o
+}
+
+/// Parses dump back into bytes.
+///
+/// Accepts output of `fn dump()` of same base, of any width, group and
+/// gutter, thus also `xxd` output for hexadecimal base. Lines without
+/// colon are taken as plain digits, as of `xxd -p`. Offsets are not checked,
+/// data are concatenated in line order.
+/// ```
+/// use huski_lib::{Base, dump::{dump, parse, Layout}};
+///
+/// let bs = b"\x1b[0m\x00\xff";
+/// let d = dump(bs, &Layout::default());
+///
+/// assert_eq!(Ok(bs.to_vec()), parse(d.as_str(), Base::Hexadecimal));
+/// ```
+pub fn parse(s: &str, b: Base) -> Result<Vec<u8>, ParseDumpError> {
+    let digits = b.digits();
+    let mut bs = Vec::new();
+
+    for (lix, line) in s.lines().enumerate() {
+        let err = ParseDumpError { line: lix + 1 };
+
+        let area = match line.split_once(':') {
+            | Some((_, rest)) => {
+                let rest = rest.strip_prefix(' ').unwrap_or(rest);
+                match rest.find("  ") {
+                    | Some(end) => &rest[..end],
+                    | None => rest,
+                }
+            },
+            | None => line,
+        };
+
+        for g in area.split_whitespace() {
+            if g.len() % digits != 0 || !g.is_ascii() {
+                return Err(err);
+            }
+
+            for ix in (0..g.len()).step_by(digits) {
+                let d = &g[ix..ix + digits];
+                let byte = u8::from_str_radix(d, b.radix()).map_err(|_| err.clone())?;
+                bs.push(byte);
+            }
+        }
+    }
+
+    Ok(bs)
+}
+
+/// Dump line is malformed.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseDumpError {
+    line: usize,
+}
+
+impl ParseDumpError {
+    /// One-based number of malformed line.
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+}
+
+impl std::fmt::Display for ParseDumpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {} is not valid dump line", self.line)
+    }
+}
+
+impl std::error::Error for ParseDumpError {}
+
+#[cfg(test)]
+mod tests_of_units {
+
+    use super::*;
+
+    mod dump {
+        use super::*;
+
+        #[test]
+        fn basic_test() {
+            let test = dump(b"0123456789abcdefXY", &Layout::default());
+            let proof = "00000000: 3031 3233 3435 3637 3839 6162 6364 6566  0123456789abcdef\n\
+                         00000010: 5859                                     XY\n";
+
+            assert_eq!(proof, test);
+        }
+
+        #[test]
+        fn empty() {
+            assert_eq!("", dump(b"", &Layout::default()));
+        }
+
+        #[test]
+        fn layout() {
+            let l = Layout {
+                width: 3,
+                group: 1,
+                base: Base::Binary,
+                gutter: Gutter::Dot,
+            };
+
+            let test = dump(b"A\x00\xffB", &l);
+            let proof = "00000000: 01000001 00000000 11111111  A..\n\
+                         00000003: 01000010                    B\n";
+
+            assert_eq!(proof, test);
+        }
+
+        #[test]
+        fn odd_group() {
+            let l = Layout {
+                width: 5,
+                group: 2,
+                base: Base::Decimal,
+                gutter: Gutter::Dot,
+            };
+
+            let test = dump(b"abcde", &l);
+            assert_eq!("00000000: 097098 099100 101  abcde\n", test);
+        }
+
+        #[test]
+        fn gutters() {
+            let bs = b"\x1b[\x7f \x80";
+            let proof = [".[. .", "<ESC>[<DEL> .", "^[[^? .", "\u{241b}[\u{2421} ."];
+
+            for (g, p) in Gutter::ALL.iter().zip(proof.iter()) {
+                let l = Layout {
+                    gutter: g.clone(),
+                    ..Layout::default()
+                };
+
+                let test = dump(bs, &l);
+                assert!(test.ends_with(format!("  {}\n", p).as_str()), "{}", test);
+            }
+        }
+
+        #[test]
+        fn zero_layout() {
+            let l = Layout {
+                width: 0,
+                group: 0,
+                ..Layout::default()
+            };
+
+            assert_eq!("00000000: 41  A\n00000001: 42  B\n", dump(b"AB", &l));
+        }
+    }
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn round_trip() {
+            let bs = (0..=255u8)
+                .chain(b"  \n".iter().cloned())
+                .collect::<Vec<u8>>();
+
+            for base in Base::ALL {
+                for gutter in Gutter::ALL {
+                    for (width, group) in [(16, 2), (7, 3), (1, 1), (8, 8)] {
+                        let l = Layout {
+                            width,
+                            group,
+                            base: base.clone(),
+                            gutter: gutter.clone(),
+                        };
+
+                        let d = dump(&bs, &l);
+                        assert_eq!(Ok(bs.clone()), parse(d.as_str(), base.clone()), "{:?}", l);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn xxd() {
+            let d = "00000000: 4865 6c6c 6f2c 2077 6f72 6c64 210a  Hello, world!.\n";
+            assert_eq!(Ok(b"Hello, world!\n".to_vec()), parse(d, Base::Hexadecimal));
+        }
+
+        #[test]
+        fn xxd_plain() {
+            let d = "48656c6c6f\n0a\n";
+            assert_eq!(Ok(b"Hello\n".to_vec()), parse(d, Base::Hexadecimal));
+        }
+
+        #[test]
+        fn space_gutter() {
+            let d = "00000000: 2020  __\n";
+            assert_eq!(Ok(b"  ".to_vec()), parse(d, Base::Hexadecimal));
+        }
+
+        #[test]
+        fn error() {
+            let d = "00000000: 4142  AB\n00000002: 4g  ?\n";
+            let test = parse(d, Base::Hexadecimal);
+
+            assert_eq!(Err(ParseDumpError { line: 2 }), test);
+            assert_eq!(2, test.unwrap_err().line());
+
+            assert_eq!(
+                Err(ParseDumpError { line: 1 }),
+                parse("414", Base::Hexadecimal)
+            );
+            assert_eq!(Err(ParseDumpError { line: 1 }), parse("999", Base::Decimal));
+        }
+    }
+}
@@ -0,0 +1,268 @@
+//! Lookup indices over `TABLE` and `ALIASES`.
+//!
+//! Indices are computed at compile time, thus lookup does not allocate.
+
+use core::cmp::Ordering;
+use huski_lib_core::case::cmp_ignore_case;
+use huski_lib_core::table::{ALIASES, TABLE};
+
+/// Field of `TABLE` entry, or alias.
+#[derive(Clone, Copy)]
+pub enum Field {
+    Human,
+    Desc,
+    Alias,
+}
+
+/// `TABLE` positions ordered by human representation.
+pub static HUMAN: [u8; 128] = index(Field::Human);
+/// `TABLE` positions ordered by description.
+pub static DESC: [u8; 128] = index(Field::Desc);
+/// `ALIASES` with their `TABLE` positions ordered by alias.
+pub static ALIAS: [(&str, u8); ALIAS_LEN] = alias_index();
+
+const ALIAS_LEN: usize = alias_len();
+
+const fn key(ix: u8, f: Field) -> &'static [u8] {
+    let entry = &TABLE[ix as usize];
+    match f {
+        | Field::Human => entry.0.as_bytes(),
+        | Field::Desc => entry.1.as_bytes(),
+        | Field::Alias => panic!("aliases are not part of table"),
+    }
+}
+
+/// Orders case-insensitively first, exactly second.
+const fn cmp(a: &[u8], b: &[u8]) -> Ordering {
+    match cmp_ignore_case(a, b) {
+        | Ordering::Equal => cmp_exact(a, b),
+        | o => o,
+    }
+}
+
+const fn cmp_exact(a: &[u8], b: &[u8]) -> Ordering {
+    let mut ix = 0;
+    while ix < a.len() && ix < b.len() {
+        if a[ix] < b[ix] {
+            return Ordering::Less;
+        }
+
+        if a[ix] > b[ix] {
+            return Ordering::Greater;
+        }
+
+        ix += 1;
+    }
+
+    cmp_len(a, b)
+}
+
+const fn cmp_len(a: &[u8], b: &[u8]) -> Ordering {
+    if a.len() < b.len() {
+        Ordering::Less
+    } else if a.len() > b.len() {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+const fn index(f: Field) -> [u8; 128] {
+    let mut index = [0; 128];
+
+    let mut ix = 0;
+    while ix < 128 {
+        index[ix] = ix as u8;
+        ix += 1;
+    }
+
+    // insertion sort, const context does not offer better
+    let mut ix = 1;
+    while ix < 128 {
+        let mut jx = ix;
+        while jx > 0 {
+            let (prev, curr) = (index[jx - 1], index[jx]);
+            if let Ordering::Greater = cmp(key(prev, f), key(curr, f)) {
+                index[jx - 1] = curr;
+                index[jx] = prev;
+                jx -= 1;
+            } else {
+                break;
+            }
+        }
+
+        ix += 1;
+    }
+
+    index
+}
+
+const fn alias_len() -> usize {
+    let mut len = 0;
+
+    let mut ix = 0;
+    while ix < 128 {
+        len += ALIASES[ix].len();
+        ix += 1;
+    }
+
+    len
+}
+
+const fn alias_index() -> [(&'static str, u8); ALIAS_LEN] {
+    let mut index = [("", 0); ALIAS_LEN];
+
+    let mut wix = 0;
+    let mut ix = 0;
+    while ix < 128 {
+        let aliases = ALIASES[ix];
+
+        let mut aix = 0;
+        while aix < aliases.len() {
+            index[wix] = (aliases[aix], ix as u8);
+            wix += 1;
+            aix += 1;
+        }
+
+        ix += 1;
+    }
+
+    // insertion sort, const context does not offer better
+    let mut ix = 1;
+    while ix < ALIAS_LEN {
+        let mut jx = ix;
+        while jx > 0 {
+            let (prev, curr) = (index[jx - 1], index[jx]);
+            if let Ordering::Greater = cmp(prev.0.as_bytes(), curr.0.as_bytes()) {
+                index[jx - 1] = curr;
+                index[jx] = prev;
+                jx -= 1;
+            } else {
+                break;
+            }
+        }
+
+        ix += 1;
+    }
+
+    index
+}
+
+/// Finds `TABLE` position for `q` within index `f`.
+///
+/// Exact match is preferred over case-insensitive one.
+pub fn find(q: &str, f: Field) -> Option<u8> {
+    let q = q.as_bytes();
+    if q.is_empty() {
+        return None;
+    }
+
+    match f {
+        | Field::Human => search(&HUMAN, |&ix| key(ix, f), |&ix| ix, q),
+        | Field::Desc => search(&DESC, |&ix| key(ix, f), |&ix| ix, q),
+        | Field::Alias => search(&ALIAS, |x| x.0.as_bytes(), |x| x.1, q),
+    }
+}
+
+/// Scans `index` for `q`, preferring exact match.
+fn search<T>(
+    index: &[T],
+    key: impl Fn(&T) -> &'static [u8],
+    code: impl Fn(&T) -> u8,
+    q: &[u8],
+) -> Option<u8> {
+    let start = index.partition_point(|x| cmp_ignore_case(key(x), q) == Ordering::Less);
+
+    let mut first = None;
+    for x in index[start..].iter() {
+        let key = key(x);
+        if cmp_ignore_case(key, q) != Ordering::Equal {
+            break;
+        }
+
+        if key == q {
+            return Some(code(x));
+        }
+
+        if first.is_none() {
+            first = Some(code(x));
+        }
+    }
+
+    first
+}
+
+#[cfg(test)]
+mod tests_of_units {
+
+    use core::cmp::Ordering;
+    use super::{Field, HUMAN, DESC, ALIAS, key, cmp, find};
+    use huski_lib_core::table::ALIASES;
+
+    #[test]
+    fn index() {
+        for (index, f) in [(&HUMAN, Field::Human), (&DESC, Field::Desc)] {
+            let mut proof = [0; 128];
+            for &ix in index.iter() {
+                proof[ix as usize] += 1;
+            }
+
+            assert!(proof.iter().all(|&c| c == 1));
+
+            for w in index.windows(2) {
+                assert_ne!(Ordering::Greater, cmp(key(w[0], f), key(w[1], f)));
+            }
+        }
+    }
+
+    #[test]
+    fn alias_index() {
+        let len = ALIASES.iter().map(|x| x.len()).sum::<usize>();
+        assert_eq!(len, ALIAS.len());
+
+        for &(a, ix) in ALIAS.iter() {
+            assert!(ALIASES[ix as usize].contains(&a));
+        }
+
+        for w in ALIAS.windows(2) {
+            assert_ne!(Ordering::Greater, cmp(w[0].0.as_bytes(), w[1].0.as_bytes()));
+        }
+    }
+
+    mod find {
+        use super::{find, Field};
+
+        #[test]
+        fn exact_preference() {
+            assert_eq!(Some(b'a'), find("a", Field::Human));
+            assert_eq!(Some(b'A'), find("A", Field::Human));
+        }
+
+        #[test]
+        fn case_insensitivity() {
+            assert_eq!(Some(127), find("del", Field::Human));
+            assert_eq!(Some(b'{'), find("LEFT BRACE", Field::Desc));
+        }
+
+        #[test]
+        fn alias() {
+            assert_eq!(Some(27), find("alt", Field::Alias));
+            assert_eq!(Some(127), find("RUBOUT", Field::Alias));
+            assert_eq!(None, find("ESC", Field::Alias));
+        }
+
+        #[test]
+        fn empty() {
+            assert_eq!(None, find("", Field::Human));
+            assert_eq!(None, find("", Field::Desc));
+            assert_eq!(None, find("", Field::Alias));
+        }
+
+        #[test]
+        fn unknown() {
+            assert_eq!(None, find("NAKK", Field::Human));
+            assert_eq!(None, find("Left", Field::Desc));
+            assert_eq!(None, find("XONN", Field::Alias));
+        }
+    }
+}
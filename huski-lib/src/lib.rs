@@ -5,10 +5,36 @@ use core::ops::RangeInclusive;
 
 pub use huski_lib_core::ranges::*;
 pub use huski_lib_core::table::TABLE;
+pub use huski_lib_core::code::{Code, CODES, OutOfTable};
+pub use huski_lib_core::iter;
+pub use huski_lib_core::set::CodeSet;
+pub use huski_lib_core::class;
+pub use huski_lib_core::case;
+pub use huski_lib_core::naming::Scheme;
+
+use huski_lib_core::code::views;
+
+pub use base::Base;
+
+mod base;
+mod index;
+pub mod annotate;
+pub mod collate;
+pub mod diff;
+pub mod dump;
+pub mod order;
+pub mod relation;
+pub mod stats;
+pub mod visible;
+
+use index::{find, Field};
 
 /// Similar to `fn codes()` but it accepts `&[Ranges]` as input
 /// and returns merged open result.
 ///
+/// `Ranges::Custom` values must fit into range 0-127, otherwise
+/// function will panic.
+/// See `iter::acquire()` for lazy counterpart.
 /// ```
 /// use huski_lib_core::ranges::Ranges;
 /// use huski_lib::acquire;
@@ -26,7 +52,9 @@ pub fn acquire(rs: &[Ranges]) -> Vec<Code> {
     let mut merged = Vec::new();
     merged.reserve_exact(len);
     for r in rs {
-        merged.extend(to_codes(r.clone()).into_iter())
+        for v in views(r.clone()) {
+            merged.extend_from_slice(v);
+        }
     }
     merged
 }
@@ -34,6 +62,9 @@ pub fn acquire(rs: &[Ranges]) -> Vec<Code> {
 /// Similar to `fn codes()` but it accepts `&[Ranges]` as input
 /// and returns open result in apart.
 ///
+/// `Ranges::Custom` values must fit into range 0-127, otherwise
+/// function will panic.
+/// See `iter::acquire_apart()` for lazy counterpart.
 /// ```
 /// use huski_lib_core::ranges::Ranges;
 /// use huski_lib::acquire_apart;
@@ -54,42 +85,73 @@ pub fn acquire_apart(rs: &[Ranges]) -> Vec<Vec<Code>> {
     many
 }
 
-fn to_codes(r: Ranges) -> Vec<Code> {
-    codes(ranges(r))
-}
+/// Similar to `fn acquire()` but overlapping ranges are merged
+/// thus each code is present at most once. Codes are in ascending order.
+///
+/// ```
+/// use huski_lib_core::ranges::Ranges;
+/// use huski_lib::acquire_distinct;
+///
+/// let rs = acquire_distinct(&[Ranges::Small, Ranges::Letters]);
+/// assert_eq!(52, rs.len());
+/// assert_eq!('A', rs[0].code() as char);
+/// assert_eq!('z', rs[51].code() as char);
+/// ```
+pub fn acquire_distinct(rs: &[Ranges]) -> Vec<Code> {
+    let mut set = CodeSet::EMPTY;
+    for r in rs {
+        set = set.union(CodeSet::of(r.clone()));
+    }
 
-/// Provides information about ASCII code
-#[derive(Debug, PartialEq, Clone)]
-pub struct Code {
-    code: u8,
-    human: &'static str,
-    desc: &'static str,
+    set.codes().cloned().collect()
 }
 
-/// Value acquisition.
-impl Code {
-    /// Decimal code value.
-    pub const fn code(&self) -> u8 {
-        self.code
-    }
+/// Similar to `fn acquire_apart()` but each code is attributed only to
+/// first group claiming it. Codes of each group are in ascending order.
+///
+/// ```
+/// use huski_lib_core::ranges::Ranges;
+/// use huski_lib::acquire_apart_distinct;
+///
+/// let rs = acquire_apart_distinct(&[Ranges::Small, Ranges::Letters]);
+/// assert_eq!(26, rs[0].len());
+/// assert_eq!(26, rs[1].len());
+/// assert_eq!('A', rs[1][0].code() as char);
+/// ```
+pub fn acquire_apart_distinct(rs: &[Ranges]) -> Vec<Vec<Code>> {
+    let mut many = Vec::new();
+    many.reserve_exact(rs.len());
+
+    let mut claimed = CodeSet::EMPTY;
+    for r in rs {
+        let set = CodeSet::of(r.clone()).difference(claimed);
+        claimed = claimed.union(set);
 
-    /// Human representation.
-    pub const fn human(&self) -> &'static str {
-        self.human
+        many.push(set.codes().cloned().collect());
     }
 
-    /// Code description.
-    pub const fn desc(&self) -> &'static str {
-        self.desc
+    many
+}
+
+fn to_codes(r: Ranges) -> Vec<Code> {
+    let mut codes = Vec::new();
+    codes.reserve_exact(len!(ranges(r.clone())));
+
+    for v in views(r) {
+        codes.extend_from_slice(v);
     }
+
+    codes
 }
 
 /// Provides `Code`s for ranges specified.
 ///
 /// Input values must fit into range 0-127, otherwise
-/// function will panic.
+/// function will panic. See `fn try_codes()` for fallible counterpart.
 ///
 /// Duplicities and input order are preserved.
+///
+/// See `iter::codes()` for lazy counterpart.
 /// ```
 /// use huski_lib_core::ranges::LETTERS;
 /// use huski_lib::codes;
@@ -103,39 +165,160 @@ pub fn codes(rs: &[RangeInclusive<usize>]) -> Vec<Code> {
     let mut codes = Vec::new();
     codes.reserve_exact(len!(rs));
 
-    let sc = codes.spare_capacity_mut();
-
-    let mut wrix = 0;
     for r in rs {
         for i in r.clone() {
-            let info = TABLE[i];
-            let code = Code {
-                code: i as u8,
-                human: info.0,
-                desc: info.1,
-            };
+            codes.push(CODES[i].clone());
+        }
+    }
+
+    codes
+}
+
+/// Fallible counterpart of `fn codes()`.
+///
+/// Instead of panicking, returns `RangeError` naming first range
+/// reaching out of table range 0-127.
+/// ```
+/// use huski_lib::try_codes;
+///
+/// assert_eq!(3, try_codes(&[0..=2]).unwrap().len());
+///
+/// let err = try_codes(&[0..=2, 120..=130]).unwrap_err();
+/// assert_eq!(&(120..=130), err.range());
+/// assert_eq!(128, err.value());
+/// ```
+pub fn try_codes(rs: &[RangeInclusive<usize>]) -> Result<Vec<Code>, RangeError> {
+    let mut len = 0;
+    for r in rs {
+        if r.is_empty() {
+            continue;
+        }
 
-            sc[wrix].write(code);
-            wrix += 1;
+        if *r.end() > 127 {
+            return Err(RangeError {
+                range: r.clone(),
+                value: *r.start().max(&128),
+            });
         }
+
+        len += r.end() - r.start() + 1;
     }
 
-    unsafe {
-        codes.set_len(wrix);
+    let mut codes = Vec::new();
+    codes.reserve_exact(len);
+
+    for r in rs {
+        for i in r.clone() {
+            codes.push(CODES[i].clone());
+        }
     }
 
-    codes
+    Ok(codes)
+}
+
+/// Range reaches out of table range 0-127.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RangeError {
+    range: RangeInclusive<usize>,
+    value: usize,
+}
+
+impl RangeError {
+    /// Offending range.
+    pub const fn range(&self) -> &RangeInclusive<usize> {
+        &self.range
+    }
+
+    /// First offending value of range.
+    pub const fn value(&self) -> usize {
+        self.value
+    }
+}
+
+impl std::fmt::Display for RangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "range {:?} reaches value {} out of table range 0-127",
+            self.range, self.value
+        )
+    }
+}
+
+impl std::error::Error for RangeError {}
+
+/// Resolves human representation, alias or description into `Code`.
+///
+/// Human representation is tried first, alias second, description third.
+/// Matching is case-insensitive but exact match takes precedence
+/// thus `"a"` resolves to small `a` while `"A"` to capital one.
+///
+/// Lookup does not allocate.
+/// ```
+/// use huski_lib::lookup;
+///
+/// assert_eq!(127, lookup("del").unwrap().code());
+/// assert_eq!('{', lookup("Left brace").unwrap().code() as char);
+/// assert_eq!(17, lookup("xon").unwrap().code());
+/// assert_eq!(None, lookup("Left"));
+/// ```
+pub fn lookup(s: &str) -> Option<&'static Code> {
+    lookup_human(s)
+        .or_else(|| lookup_alias(s))
+        .or_else(|| lookup_desc(s))
+}
+
+/// Resolves human representation, either mnemonic or glyph, into `Code`.
+///
+/// See `fn lookup()` for matching details.
+/// ```
+/// use huski_lib::lookup_human;
+///
+/// assert_eq!(21, lookup_human("NAK").unwrap().code());
+/// assert_eq!('{', lookup_human("{").unwrap().code() as char);
+/// ```
+pub fn lookup_human(h: &str) -> Option<&'static Code> {
+    find(h, Field::Human).map(to_code)
+}
+
+/// Resolves alias, i.e. `XON` for `DC1`, into `Code`.
+///
+/// See `fn lookup()` for matching details.
+/// ```
+/// use huski_lib::lookup_alias;
+///
+/// assert_eq!(127, lookup_alias("RUBOUT").unwrap().code());
+/// assert_eq!(None, lookup_alias("DEL"));
+/// ```
+pub fn lookup_alias(a: &str) -> Option<&'static Code> {
+    find(a, Field::Alias).map(to_code)
+}
+
+/// Resolves description into `Code`.
+///
+/// See `fn lookup()` for matching details.
+/// ```
+/// use huski_lib::lookup_desc;
+///
+/// assert_eq!('{', lookup_desc("left brace").unwrap().code() as char);
+/// ```
+pub fn lookup_desc(d: &str) -> Option<&'static Code> {
+    find(d, Field::Desc).map(to_code)
+}
+
+fn to_code(ix: u8) -> &'static Code {
+    &CODES[ix as usize]
 }
 
 #[cfg(test)]
 mod tests_of_units {
     use huski_lib_core::ranges::{Ranges, PRINTABLE};
 
-    use crate::{Code, to_codes as to_codes_fn, codes};
+    use crate::{to_codes as to_codes_fn, codes};
 
     mod acquire {
 
-        use huski_lib_core::ranges::{LETTERS, SYMBOLS, Ranges};
+        use huski_lib_core::ranges::{DIGITS, LETTERS, SYMBOLS, Ranges};
         use crate::{Code, acquire, codes};
 
         #[test]
@@ -152,14 +335,27 @@ mod tests_of_units {
             let s = codes(&SYMBOLS);
             let test = acquire(&[Ranges::Letters, Ranges::Symbols]);
 
-            let proof = l.into_iter().chain(s.into_iter()).collect::<Vec<Code>>();
+            let proof = l.into_iter().chain(s).collect::<Vec<Code>>();
+            assert_eq!(proof, test);
+        }
+
+        #[test]
+        fn custom_test() {
+            const NUMERIC: Ranges = Ranges::Custom(&[(45..=46)]);
+
+            let d = codes(&DIGITS);
+            let c = codes(&[(45..=46)]);
+            let test = acquire(&[Ranges::Digits, NUMERIC]);
+
+            let proof = d.into_iter().chain(c).collect::<Vec<Code>>();
             assert_eq!(proof, test);
         }
     }
 
     mod acquire_apart {
 
-        use huski_lib_core::ranges::{LETTERS, SYMBOLS, Ranges};
+        use core::ops::RangeInclusive;
+        use huski_lib_core::ranges::{DIGITS, LETTERS, SYMBOLS, Ranges};
         use crate::{acquire_apart, codes};
 
         #[test]
@@ -179,25 +375,131 @@ mod tests_of_units {
             let proof = vec![l, s];
             assert_eq!(proof, test);
         }
+
+        #[test]
+        fn custom_test() {
+            static CUSTOM: [RangeInclusive<usize>; 2] = [(0..=0), (127..=127)];
+            let test = acquire_apart(&[Ranges::Custom(&CUSTOM), Ranges::Digits]);
+
+            let proof = vec![codes(&CUSTOM), codes(&DIGITS)];
+            assert_eq!(proof, test);
+        }
     }
 
-    #[test]
-    fn to_codes() {
-        let r = Ranges::Printable;
-        assert_eq!(codes(&PRINTABLE), to_codes_fn(r.clone()));
+    mod lookup {
+        use crate::{lookup, lookup_human, lookup_alias, lookup_desc};
+
+        #[test]
+        fn basic_test() {
+            assert_eq!(Some(27), lookup("esc").map(|x| x.code()));
+            assert_eq!(Some(27), lookup("Escape").map(|x| x.code()));
+            assert_eq!(Some(27), lookup("Alt").map(|x| x.code()));
+            assert_eq!(Some(b'a'), lookup("a").map(|x| x.code()));
+            assert_eq!(None, lookup("nothing"));
+        }
+
+        #[test]
+        fn human() {
+            for i in 0..128 {
+                let human = crate::TABLE[i].0;
+                let test = lookup_human(human).unwrap();
+
+                assert_eq!(i as u8, test.code());
+                assert_eq!(human, test.human());
+            }
+        }
+
+        #[test]
+        fn alias() {
+            for i in 0..128 {
+                for &alias in huski_lib_core::table::ALIASES[i].iter() {
+                    let test = lookup_alias(alias.to_lowercase().as_str()).unwrap();
+                    assert_eq!(i as u8, test.code());
+
+                    assert_eq!(None, lookup_human(alias));
+                }
+            }
+
+            assert_eq!(None, lookup_alias(""));
+        }
+
+        #[test]
+        fn desc() {
+            for i in 0..128 {
+                let desc = crate::TABLE[i].1;
+                if desc.is_empty() {
+                    continue;
+                }
+
+                let test = lookup_desc(desc.to_uppercase().as_str()).unwrap();
+                assert_eq!(i as u8, test.code());
+            }
+
+            assert_eq!(None, lookup_desc(""));
+        }
     }
 
-    #[test]
-    fn code() {
-        let code = 99;
-        let human = "human";
-        let desc = "desc";
+    mod acquire_distinct {
+
+        use huski_lib_core::ranges::{Ranges, PRINTABLE};
+        use crate::{acquire_distinct, codes};
+
+        #[test]
+        fn basic_test() {
+            let proof = codes(&PRINTABLE);
+            let test = acquire_distinct(&[Ranges::Symbols, Ranges::Printable, Ranges::Letters]);
+
+            assert_eq!(proof, test);
+        }
+
+        #[test]
+        fn duplicities() {
+            let proof = codes(&[(65..=90)]);
+            let test = acquire_distinct(&[Ranges::Capital, Ranges::Capital]);
+
+            assert_eq!(proof, test);
+        }
 
-        let test = Code { code, human, desc };
+        #[test]
+        fn ordering() {
+            let proof = codes(&[(45..=46), (48..=57)]);
+            let test = acquire_distinct(&[Ranges::Digits, Ranges::Custom(&[(46..=46), (45..=45)])]);
 
-        assert_eq!(code, test.code);
-        assert_eq!(human, test.human);
-        assert_eq!(desc, test.desc);
+            assert_eq!(proof, test);
+        }
+
+        #[test]
+        fn empty() {
+            assert_eq!(0, acquire_distinct(&[]).len());
+        }
+    }
+
+    mod acquire_apart_distinct {
+
+        use huski_lib_core::ranges::{Ranges, CAPITAL, DIGITS, SMALL};
+        use crate::{acquire_apart_distinct, codes};
+
+        #[test]
+        fn basic_test() {
+            let proof = vec![codes(&SMALL), codes(&CAPITAL), vec![]];
+            let test = acquire_apart_distinct(&[Ranges::Small, Ranges::Letters, Ranges::Capital]);
+
+            assert_eq!(proof, test);
+        }
+
+        #[test]
+        fn first_claims() {
+            let proof = vec![codes(&DIGITS), codes(&[(65..=70), (97..=102)])];
+            let test = acquire_apart_distinct(&[Ranges::Digits, Ranges::HexDigits]);
+
+            assert_eq!(proof, test);
+        }
+    }
+
+    #[test]
+    fn to_codes() {
+        let r = Ranges::Printable;
+        assert_eq!(codes(&PRINTABLE), to_codes_fn(r.clone()));
     }
 
     mod codes {
@@ -220,9 +522,9 @@ mod tests_of_units {
 
                     reix += 1;
 
-                    assert_eq!(i as u8, t.code);
-                    assert_eq!(info.0, t.human);
-                    assert_eq!(info.1, t.desc);
+                    assert_eq!(i as u8, t.code());
+                    assert_eq!(info.0, t.human());
+                    assert_eq!(info.1, t.desc());
                 }
             }
         }
@@ -249,10 +551,56 @@ mod tests_of_units {
 
                 let t = &test[i];
 
-                assert_eq!(code as u8, t.code);
-                assert_eq!(info.0, t.human);
-                assert_eq!(info.1, t.desc);
+                assert_eq!(code as u8, t.code());
+                assert_eq!(info.0, t.human());
+                assert_eq!(info.1, t.desc());
             }
         }
     }
+
+    mod try_codes {
+        use crate::{codes, try_codes, RangeError};
+
+        #[test]
+        fn basic_test() {
+            let rs = [(0..=2), (125..=127)];
+            assert_eq!(Ok(codes(&rs)), try_codes(&rs));
+        }
+
+        #[test]
+        fn index_128() {
+            let rs = [(0..=2), (125..=128)];
+            let proof = RangeError {
+                range: 125..=128,
+                value: 128,
+            };
+
+            assert_eq!(Err(proof), try_codes(&rs));
+        }
+
+        #[test]
+        fn range_above() {
+            let rs = [(130..=140), (200..=210)];
+            let err = try_codes(&rs).unwrap_err();
+
+            assert_eq!(&(130..=140), err.range());
+            assert_eq!(130, err.value());
+        }
+
+        #[test]
+        #[allow(clippy::reversed_empty_ranges)]
+        fn empty_range() {
+            let rs = [(140..=130)];
+            assert_eq!(Ok(vec![]), try_codes(&rs));
+        }
+
+        #[test]
+        fn display() {
+            let err = try_codes(&[120..=130]).unwrap_err();
+            assert_eq!(
+                "range 120..=130 reaches value 128 out of table range 0-127",
+                err.to_string()
+            );
+        }
+    }
 }
@@ -0,0 +1,256 @@
+//! Sorting and grouping of `Code`s.
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use huski_lib_core::case::cmp_ignore_case;
+use huski_lib_core::class::is;
+use huski_lib_core::ranges::Ranges;
+use huski_lib_core::code::Code;
+
+/// Classes, mutually disjoint and covering whole table,
+/// in order of `huski` special table.
+pub static CLASSES: [Ranges; 5] = [
+    Ranges::Capital,
+    Ranges::Small,
+    Ranges::Digits,
+    Ranges::Symbols,
+    Ranges::Control,
+];
+
+/// Provides class of `c`, see `CLASSES`.
+/// ```
+/// use huski_lib::{CODES, Ranges, order::class};
+///
+/// assert_eq!(Ranges::Symbols, class(&CODES[b'~' as usize]));
+/// ```
+pub fn class(c: &Code) -> Ranges {
+    CLASSES[position(c, &CLASSES)].clone()
+}
+
+/// Sort keys.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Order<'a> {
+    /// Numeric value
+    Numeric,
+    /// Human representation, alphabetically, case-insensitive
+    Mnemonic,
+    /// Description, alphabetically, case-insensitive, empty last
+    Description,
+    /// Class, in order of `CLASSES`
+    Class,
+    /// Stick, i.e. column of 8×16 table, code value divided by 16
+    Stick,
+    /// Custom classes, in order given, codes of none last
+    Classes(&'a [Ranges]),
+}
+
+/// Grouping keys.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Grouping<'a> {
+    /// Class, one group for each of `CLASSES`
+    Class,
+    /// Stick, one group for each of 8 columns of 8×16 table
+    Stick,
+    /// Custom classes, one group for each, and last group for codes of none
+    Classes(&'a [Ranges]),
+}
+
+/// Sorts `cs` by `o`. Ties are ordered by numeric value.
+/// ```
+/// use huski_lib::{acquire, Ranges, order::{sort, Order}};
+///
+/// let mut cs = acquire(&[Ranges::Control]);
+/// sort(&mut cs, Order::Mnemonic);
+///
+/// assert_eq!("ACK", cs[0].human());
+/// assert_eq!("VT", cs[32].human());
+/// ```
+pub fn sort<T: Borrow<Code>>(cs: &mut [T], o: Order) {
+    cs.sort_by(|a, b| {
+        let (a, b) = (a.borrow(), b.borrow());
+        let ord = match &o {
+            | Order::Numeric => Ordering::Equal,
+            | Order::Mnemonic => cmp_ignore_case(a.human().as_bytes(), b.human().as_bytes())
+                .then_with(|| a.human().cmp(b.human())),
+            | Order::Description => cmp_desc(a.desc(), b.desc()),
+            | Order::Class => position(a, &CLASSES).cmp(&position(b, &CLASSES)),
+            | Order::Stick => stick(a).cmp(&stick(b)),
+            | Order::Classes(rs) => position(a, rs).cmp(&position(b, rs)),
+        };
+
+        ord.then_with(|| a.code().cmp(&b.code()))
+    });
+}
+
+/// Groups `cs` by `g`. Input order is preserved within group.
+/// ```
+/// use huski_lib::{acquire, Ranges, order::{group, Grouping}};
+///
+/// let cs = acquire(&[Ranges::Table]);
+/// let groups = group(&cs, Grouping::Stick);
+///
+/// assert_eq!(8, groups.len());
+/// assert_eq!("@", groups[4][0].human());
+/// ```
+pub fn group<T: Borrow<Code> + Clone>(cs: &[T], g: Grouping) -> Vec<Vec<T>> {
+    let len = match &g {
+        | Grouping::Class => CLASSES.len(),
+        | Grouping::Stick => 8,
+        | Grouping::Classes(rs) => rs.len() + 1,
+    };
+
+    let mut groups = vec![Vec::new(); len];
+    for c in cs {
+        let code = c.borrow();
+        let ix = match &g {
+            | Grouping::Class => position(code, &CLASSES),
+            | Grouping::Stick => stick(code),
+            | Grouping::Classes(rs) => position(code, rs),
+        };
+
+        groups[ix].push(c.clone());
+    }
+
+    groups
+}
+
+fn cmp_desc(a: &str, b: &str) -> Ordering {
+    match (a.is_empty(), b.is_empty()) {
+        | (true, true) => Ordering::Equal,
+        | (true, false) => Ordering::Greater,
+        | (false, true) => Ordering::Less,
+        | (false, false) => cmp_ignore_case(a.as_bytes(), b.as_bytes()).then_with(|| a.cmp(b)),
+    }
+}
+
+fn stick(c: &Code) -> usize {
+    c.code() as usize >> 4
+}
+
+/// Position of first of `rs` containing `c`, `rs.len()` for none.
+fn position(c: &Code, rs: &[Ranges]) -> usize {
+    rs.iter()
+        .position(|r| is(r.clone(), c.code()))
+        .unwrap_or(rs.len())
+}
+
+#[cfg(test)]
+mod tests_of_units {
+
+    use huski_lib_core::ranges::Ranges;
+    use crate::{acquire, Code, CODES};
+    use super::*;
+
+    fn codes(s: &str) -> Vec<Code> {
+        s.bytes().map(|x| CODES[x as usize].clone()).collect()
+    }
+
+    fn human(cs: &[Code]) -> String {
+        cs.iter().map(|x| x.human()).collect()
+    }
+
+    #[test]
+    fn class_test() {
+        for c in CODES.iter() {
+            let class = class(c);
+            let count = CLASSES
+                .iter()
+                .filter(|r| is((*r).clone(), c.code()))
+                .count();
+
+            assert_eq!(1, count);
+            assert!(is(class, c.code()));
+        }
+    }
+
+    mod sort {
+        use super::*;
+
+        #[test]
+        fn numeric() {
+            let mut test = codes("cAb1");
+            sort(&mut test, Order::Numeric);
+            assert_eq!("1Abc", human(&test));
+        }
+
+        #[test]
+        fn mnemonic() {
+            let mut test = codes("bBaA");
+            sort(&mut test, Order::Mnemonic);
+            assert_eq!("AaBb", human(&test));
+
+            let mut test = acquire(&[Ranges::Control]);
+            sort(&mut test, Order::Mnemonic);
+            let test = test.iter().map(|x| x.human()).collect::<Vec<_>>();
+            assert_eq!(["ACK", "BEL", "BS", "CAN", "CR"], test[..5]);
+        }
+
+        #[test]
+        fn description() {
+            let mut test = codes("a{(}!");
+            sort(&mut test, Order::Description);
+            assert_eq!("!{(}a", human(&test));
+        }
+
+        #[test]
+        fn class() {
+            let mut test = codes("~a0\x07Z");
+            sort(&mut test, Order::Class);
+            assert_eq!("Za0~BEL", human(&test));
+        }
+
+        #[test]
+        fn stick() {
+            let mut test = codes("a!A\x01");
+            sort(&mut test, Order::Stick);
+            assert_eq!("SOH!Aa", human(&test));
+        }
+
+        #[test]
+        fn classes() {
+            let mut test = codes("a0Z_");
+            sort(&mut test, Order::Classes(&[Ranges::Digits, Ranges::Small]));
+            assert_eq!("0aZ_", human(&test));
+        }
+
+        #[test]
+        fn references() {
+            let cs = codes("ba");
+            let mut test = cs.iter().collect::<Vec<&Code>>();
+            sort(&mut test, Order::Numeric);
+
+            assert_eq!("a", test[0].human());
+        }
+    }
+
+    mod group {
+        use super::*;
+
+        #[test]
+        fn class() {
+            let test = group(&codes("a0Z~\x00b"), Grouping::Class);
+            let test = test.iter().map(|x| human(x)).collect::<Vec<_>>();
+
+            assert_eq!(vec!["Z", "ab", "0", "~", "NUL"], test);
+        }
+
+        #[test]
+        fn stick() {
+            let test = group(&acquire(&[Ranges::Table]), Grouping::Stick);
+
+            assert_eq!(8, test.len());
+            for (ix, g) in test.iter().enumerate() {
+                assert_eq!(16, g.len());
+                assert!(g.iter().all(|x| x.code() as usize / 16 == ix));
+            }
+        }
+
+        #[test]
+        fn classes() {
+            let test = group(&codes("a0Z_1"), Grouping::Classes(&[Ranges::Digits]));
+            let test = test.iter().map(|x| human(x)).collect::<Vec<_>>();
+
+            assert_eq!(vec!["01", "aZ_"], test);
+        }
+    }
+}
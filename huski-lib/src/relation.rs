@@ -0,0 +1,143 @@
+//! Relations among `Code`s.
+
+use huski_lib_core::code::{Code, CODES};
+
+/// Bracket and quote pairs, opening first. Quotes pair with themselves.
+pub static PAIRS: [(u8, u8); 7] = [
+    (b'(', b')'),
+    (b'[', b']'),
+    (b'{', b'}'),
+    (b'<', b'>'),
+    (b'"', b'"'),
+    (b'\'', b'\''),
+    (b'`', b'`'),
+];
+
+/// Provides case counterpart of letter, code with bit 5 flipped.
+/// ```
+/// use huski_lib::{CODES, relation::case};
+///
+/// assert_eq!('a', case(&CODES[b'A' as usize]).unwrap().code() as char);
+/// assert_eq!(None, case(&CODES[b'@' as usize]));
+/// ```
+pub fn case(c: &Code) -> Option<&'static Code> {
+    if c.code().is_ascii_alphabetic() {
+        Some(&CODES[(c.code() ^ 0x20) as usize])
+    } else {
+        None
+    }
+}
+
+/// Provides matching counterpart of bracket or quote, see `PAIRS`.
+/// ```
+/// use huski_lib::{CODES, relation::pair};
+///
+/// assert_eq!('{', pair(&CODES[b'}' as usize]).unwrap().code() as char);
+/// assert_eq!('"', pair(&CODES[b'"' as usize]).unwrap().code() as char);
+/// ```
+pub fn pair(c: &Code) -> Option<&'static Code> {
+    let code = c.code();
+    PAIRS.iter().find_map(|&(o, e)| {
+        if o == code {
+            Some(&CODES[e as usize])
+        } else if e == code {
+            Some(&CODES[o as usize])
+        } else {
+            None
+        }
+    })
+}
+
+/// Checks whether `c` opens pair, see `PAIRS`. Quotes both open and close.
+pub fn is_opening(c: &Code) -> bool {
+    PAIRS.iter().any(|x| x.0 == c.code())
+}
+
+/// Provides Ctrl-key counterpart, i.e. `[` for ESC and ESC for `[`.
+///
+/// Control codes 0-31 pair with codes 64-95, bit 6 flipped, DEL pairs with `?`.
+/// ```
+/// use huski_lib::{CODES, relation::ctrl};
+///
+/// assert_eq!("[", ctrl(&CODES[27]).unwrap().human());
+/// assert_eq!("DEL", ctrl(&CODES[b'?' as usize]).unwrap().human());
+/// assert_eq!(None, ctrl(&CODES[b'a' as usize]));
+/// ```
+pub fn ctrl(c: &Code) -> Option<&'static Code> {
+    let code = match c.code() {
+        | 127 => b'?',
+        | b'?' => 127,
+        | x @ (0..=31 | 64..=95) => x ^ 0x40,
+        | _ => return None,
+    };
+
+    Some(&CODES[code as usize])
+}
+
+#[cfg(test)]
+mod tests_of_units {
+
+    use super::*;
+
+    fn code(c: u8) -> &'static Code {
+        &CODES[c as usize]
+    }
+
+    #[test]
+    fn case_test() {
+        for c in CODES.iter() {
+            let test = case(c);
+            if c.code().is_ascii_alphabetic() {
+                let test = test.unwrap();
+                assert!(test.code().eq_ignore_ascii_case(&c.code()));
+                assert_ne!(c.code(), test.code());
+                assert_eq!(Some(c), case(test));
+            } else {
+                assert_eq!(None, test);
+            }
+        }
+    }
+
+    #[test]
+    fn pair_test() {
+        assert_eq!(Some(code(b')')), pair(code(b'(')));
+        assert_eq!(Some(code(b'[')), pair(code(b']')));
+        assert_eq!(Some(code(b'>')), pair(code(b'<')));
+        assert_eq!(Some(code(b'\'')), pair(code(b'\'')));
+        assert_eq!(None, pair(code(b'/')));
+
+        for c in CODES.iter() {
+            if let Some(p) = pair(c) {
+                assert_eq!(Some(c), pair(p));
+            }
+        }
+    }
+
+    #[test]
+    fn is_opening_test() {
+        assert!(is_opening(code(b'{')));
+        assert!(!is_opening(code(b'}')));
+        assert!(is_opening(code(b'`')));
+        assert!(!is_opening(code(b'a')));
+    }
+
+    #[test]
+    fn ctrl_test() {
+        assert_eq!(Some(code(b'@')), ctrl(code(0)));
+        assert_eq!(Some(code(b'_')), ctrl(code(31)));
+        assert_eq!(Some(code(b'?')), ctrl(code(127)));
+        assert_eq!(Some(code(27)), ctrl(code(b'[')));
+        assert_eq!(None, ctrl(code(b' ')));
+        assert_eq!(None, ctrl(code(b'`')));
+
+        for c in CODES.iter() {
+            if let Some(p) = ctrl(c) {
+                assert_eq!(Some(c), ctrl(p));
+                assert_ne!(
+                    c.code() < 32 || c.code() == 127,
+                    p.code() < 32 || p.code() == 127
+                );
+            }
+        }
+    }
+}
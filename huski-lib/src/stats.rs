@@ -0,0 +1,312 @@
+//! Byte statistics of arbitrary input.
+//!
+//! Lines are terminated by line feed, or carriage return followed by line
+//! feed, same as in `annotate` and `diff`. Carriage return not followed by
+//! line feed does not end line, it is counted as ending only. Line length
+//! excludes terminator.
+
+use std::io::{BufRead, BufReader, Read, Result};
+use huski_lib_core::ranges::{ranges, Ranges};
+
+/// Number of longest lines kept.
+pub const LONGEST_LEN: usize = 5;
+
+/// Line ending counts.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Endings {
+    /// Line feed not preceded by carriage return
+    pub lf: usize,
+    /// Carriage return followed by line feed
+    pub crlf: usize,
+    /// Carriage return not followed by line feed, part of line
+    pub cr: usize,
+}
+
+/// Statistics of bytes, fed incrementally.
+/// ```
+/// use huski_lib::{Ranges, stats::Stats};
+///
+/// let mut s = Stats::new();
+/// s.update(b"Hi\r");
+/// s.update(b"\nthere\xff\n");
+///
+/// assert_eq!(11, s.total());
+/// assert_eq!(1, s.code(b'H'));
+/// assert_eq!(6, s.count(Ranges::Small));
+/// assert_eq!(1, s.non_ascii());
+/// assert_eq!((1, 1), (s.endings().crlf, s.endings().lf));
+/// assert_eq!(&[(2, 6), (1, 2)], s.longest());
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct Stats {
+    codes: [usize; 128],
+    non_ascii: usize,
+    total: usize,
+    endings: Endings,
+    longest: Vec<(usize, usize)>,
+    line: usize,
+    length: usize,
+    cr: bool,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Stats {
+    /// Statistics of empty input.
+    pub const fn new() -> Self {
+        Stats {
+            codes: [0; 128],
+            non_ascii: 0,
+            total: 0,
+            endings: Endings { lf: 0, crlf: 0, cr: 0 },
+            longest: Vec::new(),
+            line: 1,
+            length: 0,
+            cr: false,
+        }
+    }
+
+    /// Accounts `bs` as continuation of input.
+    pub fn update(&mut self, bs: &[u8]) {
+        for &b in bs {
+            self.total += 1;
+            match self.codes.get_mut(b as usize) {
+                | Some(c) => *c += 1,
+                | None => self.non_ascii += 1,
+            }
+
+            if self.cr {
+                self.cr = false;
+                if b == b'\n' {
+                    self.endings.crlf += 1;
+                    self.end_line();
+                    continue;
+                }
+
+                self.endings.cr += 1;
+                self.length += 1;
+            }
+
+            match b {
+                | b'\n' => {
+                    self.endings.lf += 1;
+                    self.end_line();
+                },
+                | b'\r' => self.cr = true,
+                | _ => self.length += 1,
+            }
+        }
+    }
+
+    /// Count of bytes.
+    pub const fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Count of `b`, 0 for bytes out of table range 0-127.
+    pub const fn code(&self, b: u8) -> usize {
+        if b > 127 {
+            0
+        } else {
+            self.codes[b as usize]
+        }
+    }
+
+    /// Count of bytes within `r`. Values of `Ranges::Custom` above 127 are not counted.
+    pub fn count(&self, r: Ranges) -> usize {
+        ranges(r)
+            .iter()
+            .filter_map(|x| self.codes.get(*x.start()..=*x.end().min(&127)))
+            .map(|x| x.iter().sum::<usize>())
+            .sum()
+    }
+
+    /// Count of bytes out of table range 0-127.
+    pub const fn non_ascii(&self) -> usize {
+        self.non_ascii
+    }
+
+    /// Count of lines, last line counts only if not empty.
+    pub const fn lines(&self) -> usize {
+        if self.length > 0 || self.cr {
+            self.line
+        } else {
+            self.line - 1
+        }
+    }
+
+    /// Line ending counts. Carriage return ending input counts only after `fn finish()`.
+    pub const fn endings(&self) -> &Endings {
+        &self.endings
+    }
+
+    /// Up to `LONGEST_LEN` longest lines as one-based line and length,
+    /// longest first, earlier first for same length.
+    ///
+    /// Unterminated last line counts only after `fn finish()`.
+    pub fn longest(&self) -> &[(usize, usize)] {
+        self.longest.as_slice()
+    }
+
+    /// Accounts end of input.
+    pub fn finish(&mut self) {
+        if self.cr {
+            self.endings.cr += 1;
+            self.length += 1;
+            self.cr = false;
+        }
+
+        if self.length > 0 {
+            self.end_line();
+        }
+    }
+
+    fn end_line(&mut self) {
+        let ix = self.longest.partition_point(|x| x.1 >= self.length);
+        if ix < LONGEST_LEN {
+            self.longest.insert(ix, (self.line, self.length));
+            self.longest.truncate(LONGEST_LEN);
+        }
+
+        self.line += 1;
+        self.length = 0;
+    }
+}
+
+/// Statistics of `bs`.
+/// ```
+/// use huski_lib::stats::stats;
+///
+/// let s = stats(b"ab\ncde");
+/// assert_eq!(2, s.lines());
+/// assert_eq!(&[(2, 3), (1, 2)], s.longest());
+/// ```
+pub fn stats(bs: &[u8]) -> Stats {
+    let mut s = Stats::new();
+    s.update(bs);
+    s.finish();
+    s
+}
+
+/// Statistics of all bytes read from `r`. Reading is buffered.
+pub fn stats_read<R: Read>(r: R) -> Result<Stats> {
+    let mut r = BufReader::new(r);
+    let mut s = Stats::new();
+
+    loop {
+        let buff = r.fill_buf()?;
+        if buff.is_empty() {
+            break;
+        }
+
+        s.update(buff);
+        let len = buff.len();
+        r.consume(len);
+    }
+
+    s.finish();
+    Ok(s)
+}
+
+#[cfg(test)]
+mod tests_of_units {
+
+    use super::*;
+
+    #[test]
+    fn basic_test() {
+        let test = stats(b"Ab 1!\t\r\n\x00\xc2\xa0\n");
+
+        assert_eq!(12, test.total());
+        assert_eq!(2, test.non_ascii());
+        assert_eq!(1, test.count(Ranges::Capital));
+        assert_eq!(1, test.count(Ranges::Small));
+        assert_eq!(1, test.count(Ranges::Digits));
+        assert_eq!(2, test.count(Ranges::Symbols));
+        assert_eq!(5, test.count(Ranges::Control));
+        assert_eq!(10, test.count(Ranges::Table));
+        assert_eq!(2, test.code(b'\n'));
+        assert_eq!(0, test.code(0xc2));
+        assert_eq!(2, test.lines());
+    }
+
+    #[test]
+    fn empty() {
+        let test = stats(b"");
+        assert_eq!(0, test.total());
+        assert_eq!(0, test.lines());
+        assert_eq!(Endings::default(), *test.endings());
+        assert!(test.longest().is_empty());
+    }
+
+    #[test]
+    fn endings() {
+        let test = stats(b"a\r\nb\nc\rd\r\n\r");
+        let proof = Endings { lf: 1, crlf: 2, cr: 2 };
+
+        assert_eq!(proof, *test.endings());
+        assert_eq!(4, test.lines());
+        assert_eq!(&[(3, 3), (1, 1), (2, 1), (4, 1)], test.longest());
+    }
+
+    #[test]
+    fn cr_only() {
+        // lone carriage return does not end line
+        let test = stats(b"a\rbb\rc\r");
+        let proof = Endings { lf: 0, crlf: 0, cr: 3 };
+
+        assert_eq!(proof, *test.endings());
+        assert_eq!(1, test.lines());
+        assert_eq!(&[(1, 7)], test.longest());
+
+        let mut test = Stats::new();
+        test.update(b"\r\r");
+        assert_eq!(1, test.lines());
+        assert_eq!(1, test.endings().cr);
+
+        test.finish();
+        assert_eq!(1, test.lines());
+        assert_eq!(2, test.endings().cr);
+        assert_eq!(&[(1, 2)], test.longest());
+    }
+
+    #[test]
+    fn split_crlf() {
+        let mut test = Stats::new();
+        test.update(b"ab\r");
+        test.update(b"\n");
+        test.finish();
+
+        assert_eq!(stats(b"ab\r\n"), test);
+        assert_eq!(1, test.endings().crlf);
+        assert_eq!(0, test.endings().cr);
+    }
+
+    #[test]
+    fn longest() {
+        let input = (1..=8)
+            .map(|x| "x".repeat(x % 4))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        let test = stats(input.as_bytes());
+        assert_eq!(&[(3, 3), (7, 3), (2, 2), (6, 2), (1, 1)], test.longest());
+        assert_eq!(7, test.lines());
+    }
+
+    #[test]
+    fn custom() {
+        const OUT: Ranges = Ranges::Custom(&[(97..=97), (120..=200)]);
+        assert_eq!(2, stats(b"a|\xff").count(OUT));
+    }
+
+    #[test]
+    fn read() {
+        let bs = (0..20000).map(|x| (x % 256) as u8).collect::<Vec<u8>>();
+        assert_eq!(stats(&bs), stats_read(bs.as_slice()).unwrap());
+    }
+}
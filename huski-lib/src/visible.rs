@@ -0,0 +1,373 @@
+//! Visible rendering of invisible codes, similar to `cat -A`.
+//!
+//! Control codes render in chosen style, printable codes as they are.
+//! Line feed renders too, yet it is followed by actual line feed to keep lines.
+//! Bytes out of table range 0-127 render in meta notation, `M-` followed by
+//! rendering of byte with high bit cleared, i.e. `M-a` for 0xe1.
+
+use std::io::{Error, ErrorKind, Read, Result, Write};
+use huski_lib_core::code::CODES;
+
+/// Rendering style of control codes.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Style {
+    /// Caret notation, i.e. `^M`
+    Caret,
+    /// Mnemonic in angle brackets, i.e. `<CR>`
+    Mnemonic,
+    /// C escape sequence, i.e. `\r`
+    Escape,
+    /// Unicode Control Pictures glyph, i.e. `␍`
+    Picture,
+}
+
+impl Style {
+    /// All variants.
+    pub const ALL: [Style; 4] = [Style::Caret, Style::Mnemonic, Style::Escape, Style::Picture];
+
+    /// Name, suitable for identification.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            | Style::Caret => "caret",
+            | Style::Mnemonic => "mnemonic",
+            | Style::Escape => "escape",
+            | Style::Picture => "picture",
+        }
+    }
+}
+
+/// Renders `b` by `s` into `o`.
+/// ```
+/// use huski_lib::visible::{render, Style};
+///
+/// let mut o = Vec::new();
+/// for b in b"a\r\xe1" {
+///     render(*b, &Style::Mnemonic, &mut o);
+/// }
+///
+/// assert_eq!(b"a<CR>M-a", o.as_slice());
+/// ```
+pub fn render(b: u8, s: &Style, o: &mut Vec<u8>) {
+    if b > 127 {
+        o.extend_from_slice(b"M-");
+        render(b & 0x7f, s, o);
+        return;
+    }
+
+    if (32..127).contains(&b) {
+        o.push(b);
+        return;
+    }
+
+    let c = &CODES[b as usize];
+    match s {
+        | Style::Caret => o.extend_from_slice(c.caret().unwrap_or_default().as_bytes()),
+        | Style::Mnemonic => {
+            o.push(b'<');
+            o.extend_from_slice(c.human().as_bytes());
+            o.push(b'>');
+        },
+        | Style::Escape => o.extend_from_slice(c.c_escape().as_bytes()),
+        | Style::Picture => o.extend_from_slice(c.picture().unwrap_or_default().as_bytes()),
+    }
+
+    if b == b'\n' {
+        o.push(b'\n');
+    }
+}
+
+/// Renders each byte of `bs` by `s`.
+/// ```
+/// use huski_lib::visible::{visible, Style};
+///
+/// assert_eq!(b"a^I^J\n".to_vec(), visible(b"a\t\n", &Style::Caret));
+/// assert_eq!(b"a\\t\\n\n".to_vec(), visible(b"a\t\n", &Style::Escape));
+/// ```
+pub fn visible(bs: &[u8], s: &Style) -> Vec<u8> {
+    let mut o = Vec::with_capacity(bs.len());
+    for &b in bs {
+        render(b, s, &mut o);
+    }
+
+    o
+}
+
+/// Writer adapter rendering all bytes written by `Style` before passing
+/// them to inner writer.
+///
+/// Rendered bytes inner writer did not take are kept and written first on
+/// next `write` or on `flush`.
+/// ```
+/// use std::io::Write;
+/// use huski_lib::visible::{Style, VisibleWriter};
+///
+/// let mut w = VisibleWriter::new(Vec::new(), Style::Picture);
+/// w.write_all(b"\x1b[0m").unwrap();
+///
+/// assert_eq!("\u{241b}[0m".as_bytes(), w.into_inner().as_slice());
+/// ```
+pub struct VisibleWriter<W> {
+    inner: W,
+    style: Style,
+    buff: Vec<u8>,
+}
+
+impl<W: Write> VisibleWriter<W> {
+    /// Wraps `inner` rendering by `style`.
+    pub const fn new(inner: W, style: Style) -> Self {
+        VisibleWriter {
+            inner,
+            style,
+            buff: Vec::new(),
+        }
+    }
+
+    /// Inner writer. Rendered bytes not yet written are lost, see `fn flush()`.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Writes kept rendered bytes into inner writer.
+    fn drain(&mut self) -> Result<()> {
+        while !self.buff.is_empty() {
+            match self.inner.write(&self.buff) {
+                | Ok(0) => {
+                    return Err(Error::new(
+                        ErrorKind::WriteZero,
+                        "failed to write rendered bytes",
+                    ))
+                },
+                | Ok(len) => _ = self.buff.drain(..len),
+                | Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                | Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for VisibleWriter<W> {
+    /// Whole `buf` is taken once kept rendered bytes are written, error
+    /// writing them means nothing of `buf` is taken. Error writing rendering
+    /// of `buf` is deferred to next call.
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.drain()?;
+
+        for &b in buf {
+            render(b, &self.style, &mut self.buff);
+        }
+
+        // `buf` is taken, unwritten rest is kept
+        _ = self.drain();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.drain()?;
+        self.inner.flush()
+    }
+}
+
+/// Reader adapter rendering all bytes read from inner reader by `Style`.
+/// ```
+/// use std::io::Read;
+/// use huski_lib::visible::{Style, VisibleReader};
+///
+/// let mut r = VisibleReader::new(&b"a\tb\r\n"[..], Style::Mnemonic);
+/// let mut s = String::new();
+/// r.read_to_string(&mut s).unwrap();
+///
+/// assert_eq!("a<HT>b<CR><LF>\n", s);
+/// ```
+pub struct VisibleReader<R> {
+    inner: R,
+    style: Style,
+    buff: Vec<u8>,
+    pos: usize,
+}
+
+impl<R: Read> VisibleReader<R> {
+    /// Wraps `inner` rendering by `style`.
+    pub const fn new(inner: R, style: Style) -> Self {
+        VisibleReader {
+            inner,
+            style,
+            buff: Vec::new(),
+            pos: 0,
+        }
+    }
+
+    /// Inner reader. Rendered bytes not yet read are lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for VisibleReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        if self.pos == self.buff.len() {
+            let mut raw = [0; 1024];
+            let len = self.inner.read(&mut raw)?;
+
+            self.buff.clear();
+            self.pos = 0;
+            for &b in &raw[..len] {
+                render(b, &self.style, &mut self.buff);
+            }
+        }
+
+        let len = buf.len().min(self.buff.len() - self.pos);
+        buf[..len].copy_from_slice(&self.buff[self.pos..self.pos + len]);
+        self.pos += len;
+
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests_of_units {
+
+    use super::*;
+
+    mod render {
+        use super::*;
+
+        #[test]
+        fn styles() {
+            let proof = [
+                "a^M^J\n^?M-^[M-a ",
+                "a<CR><LF>\n<DEL>M-<ESC>M-a ",
+                "a\\r\\n\n\\x7fM-\\x1bM-a ",
+                "a\u{240d}\u{240a}\n\u{2421}M-\u{241b}M-a ",
+            ];
+
+            for (s, p) in Style::ALL.iter().zip(proof.iter()) {
+                let test = visible(b"a\r\n\x7f\x9b\xe1 ", s);
+                assert_eq!(p.as_bytes(), test.as_slice(), "{:?}", s);
+            }
+        }
+
+        #[test]
+        fn all_controls_visible() {
+            for s in Style::ALL.iter() {
+                for b in (0..32).chain([127]) {
+                    let test = visible(&[b], s);
+                    assert!(test.len() > 1, "{} {:?}", b, s);
+                    assert!(!test[..test.len() - 1].iter().any(|x| x.is_ascii_control()));
+                }
+            }
+        }
+    }
+
+    mod writer {
+        use super::*;
+
+        #[test]
+        fn basic_test() {
+            let mut w = VisibleWriter::new(Vec::new(), Style::Caret);
+            assert_eq!(2, w.write(b"\t\r").unwrap());
+            w.write_all(b"x\n").unwrap();
+            w.flush().unwrap();
+
+            assert_eq!(b"^I^Mx^J\n", w.into_inner().as_slice());
+        }
+
+        /// Takes at most 2 bytes per write, fails on writes listed.
+        struct Choppy {
+            o: Vec<u8>,
+            writes: usize,
+            failures: &'static [(usize, ErrorKind)],
+        }
+
+        impl Write for Choppy {
+            fn write(&mut self, buf: &[u8]) -> Result<usize> {
+                let ix = self.writes;
+                self.writes += 1;
+
+                if let Some((_, k)) = self.failures.iter().find(|x| x.0 == ix) {
+                    return Err(Error::from(*k));
+                }
+
+                let len = buf.len().min(2);
+                self.o.extend_from_slice(&buf[..len]);
+                Ok(len)
+            }
+
+            fn flush(&mut self) -> Result<()> {
+                Ok(())
+            }
+        }
+
+        #[test]
+        fn partial_writes() {
+            let inner = Choppy {
+                o: Vec::new(),
+                writes: 0,
+                failures: &[(1, ErrorKind::Interrupted), (4, ErrorKind::Interrupted)],
+            };
+
+            let mut w = VisibleWriter::new(inner, Style::Mnemonic);
+            w.write_all(b"a\tb\r\n").unwrap();
+            w.flush().unwrap();
+
+            let proof = visible(b"a\tb\r\n", &Style::Mnemonic);
+            assert_eq!(proof, w.into_inner().o);
+        }
+
+        #[test]
+        fn failing_writes() {
+            let inner = Choppy {
+                o: Vec::new(),
+                writes: 0,
+                failures: &[(1, ErrorKind::Other), (2, ErrorKind::Other)],
+            };
+
+            let mut w = VisibleWriter::new(inner, Style::Caret);
+
+            // rendering of "\t\r" is "^I^M", "^M" is kept after failure
+            assert_eq!(2, w.write(b"\t\r").unwrap());
+            assert_eq!(b"^I", w.inner.o.as_slice());
+
+            // kept bytes fail again, nothing is taken
+            assert!(w.write(b"x").is_err());
+
+            assert_eq!(1, w.write(b"x").unwrap());
+            w.flush().unwrap();
+            assert_eq!(b"^I^Mx", w.into_inner().o.as_slice());
+        }
+    }
+
+    mod reader {
+        use super::*;
+
+        #[test]
+        fn basic_test() {
+            let input = [b'\0'; 3000];
+            let mut r = VisibleReader::new(&input[..], Style::Mnemonic);
+
+            let mut test = Vec::new();
+            r.read_to_end(&mut test).unwrap();
+
+            assert_eq!(visible(&input, &Style::Mnemonic), test);
+        }
+
+        #[test]
+        fn small_buffer() {
+            let mut r = VisibleReader::new(&b"\x1b"[..], Style::Mnemonic);
+            let mut buf = [0; 2];
+
+            assert_eq!(2, r.read(&mut buf).unwrap());
+            assert_eq!(b"<E", &buf);
+            assert_eq!(2, r.read(&mut buf).unwrap());
+            assert_eq!(b"SC", &buf);
+            assert_eq!(1, r.read(&mut buf).unwrap());
+            assert_eq!(b'>', buf[0]);
+            assert_eq!(0, r.read(&mut buf).unwrap());
+        }
+    }
+}
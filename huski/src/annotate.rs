@@ -0,0 +1,70 @@
+use std::fs::File;
+use std::io::{stdin, Read, Write};
+use huski_lib::annotate::{annotate_read, Annotation};
+use huski_lib::Base;
+use crate::INDENT;
+
+const ERR_OPEN: &str = "Err:: problem opening file";
+const ERR_READ: &str = "Err:: problem reading input";
+const ERR_WRITE: &str = "Err:: problem writing output";
+
+/// Writes row for each byte of file given in `args`, or of standard input if none is given.
+///
+/// Rows are written as bytes are read, thus input of any size suits.
+pub fn annotate(args: &[String], b: Base, o: &mut impl Write) -> Result<(), &'static str> {
+    let path = args.iter().find(|x| !x.starts_with('-'));
+    let input: Box<dyn Read> = match path {
+        | Some(p) => Box::new(File::open(p).map_err(|_| ERR_OPEN)?),
+        | None => Box::new(stdin().lock()),
+    };
+
+    let mut header = String::with_capacity(200);
+    header.push('\n');
+    header.push_str(INDENT);
+    header.push_str("   OFFSET | LINE:COL |  NUMERIC   | HUMAN | CLASS");
+    header.push('\n');
+    header.push_str(INDENT);
+    header.push_str("-------------------------------------------------------");
+    o.write_all(header.as_bytes()).map_err(|_| ERR_WRITE)?;
+
+    let mut buff = String::with_capacity(100);
+    for a in annotate_read(input) {
+        let a = a.map_err(|_| ERR_READ)?;
+
+        buff.clear();
+        row(&a, &b, &mut buff);
+        o.write_all(buff.as_bytes()).map_err(|_| ERR_WRITE)?;
+    }
+
+    o.write_all(b"\n\n").map_err(|_| ERR_WRITE)?;
+    o.flush().map_err(|_| ERR_WRITE)
+}
+
+fn row(a: &Annotation, b: &Base, o: &mut String) {
+    let byte = a.byte();
+    let numeric = match b {
+        | Base::Binary => format!("0b{:>08b}", byte),
+        | Base::Octal => format!("0o{:>03o}", byte),
+        | Base::Decimal => format!("{}", byte),
+        | Base::Hexadecimal => format!("0x{:>02x}", byte),
+    };
+
+    let (human, class) = match a.code() {
+        | Some(c) => (c.human(), a.class().map(|x| x.description()).unwrap_or("-")),
+        | None => ("-", "non-ASCII"),
+    };
+
+    let position = format!("{}:{}", a.line(), a.column());
+    let row = format!(
+        "{:>9} | {:<8} | {:^10} | {:^5} | {}",
+        a.offset(),
+        position,
+        numeric,
+        human,
+        class
+    );
+
+    o.push('\n');
+    o.push_str(INDENT);
+    o.push_str(row.as_str());
+}
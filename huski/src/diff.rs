@@ -0,0 +1,66 @@
+use std::fs::read;
+use huski_lib::diff::{diff as diff_lib, mnemonics};
+use crate::INDENT;
+
+const ERR_FILES: &str = "Err:: two files to compare expected";
+const ERR_OPEN: &str = "Err:: problem reading file";
+
+/// Writes row for each difference of two files given in `args`.
+///
+/// Returns count of differences.
+pub fn diff(args: &[String], o: &mut String) -> Result<usize, &'static str> {
+    let paths = args
+        .iter()
+        .filter(|x| !x.starts_with('-'))
+        .collect::<Vec<&String>>();
+
+    if paths.len() != 2 {
+        return Err(ERR_FILES);
+    }
+
+    let left = read(paths[0]).map_err(|_| ERR_OPEN)?;
+    let right = read(paths[1]).map_err(|_| ERR_OPEN)?;
+
+    let ds = diff_lib(&left, &right);
+    if ds.is_empty() {
+        o.push('\n');
+        o.push_str(INDENT);
+        o.push_str("No differences found.");
+        return Ok(0);
+    }
+
+    o.push('\n');
+    o.push_str(INDENT);
+    o.push_str(" LEFT L:C | RIGHT L:C |  LEFT OFF | RIGHT OFF | LEFT → RIGHT");
+    o.push('\n');
+    o.push_str(INDENT);
+    o.push_str("-------------------------------------------------------------------");
+
+    for d in ds.iter() {
+        let (l_line, r_line) = d.lines();
+        let (l_off, r_off) = d.offsets();
+        let row = format!(
+            "{:<9} | {:<9} | {:>9} | {:>9} | {} → {}",
+            format!("{}:{}", l_line, d.column()),
+            format!("{}:{}", r_line, d.column()),
+            l_off,
+            r_off,
+            span(d.left()),
+            span(d.right())
+        );
+
+        o.push('\n');
+        o.push_str(INDENT);
+        o.push_str(row.as_str());
+    }
+
+    Ok(ds.len())
+}
+
+fn span(bs: &[u8]) -> String {
+    if bs.is_empty() {
+        String::from("-")
+    } else {
+        mnemonics(bs)
+    }
+}
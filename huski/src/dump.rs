@@ -0,0 +1,79 @@
+use std::fs::File;
+use std::io::{stdin, Read};
+use huski_lib::Base;
+use huski_lib::dump::{dump as dump_lib, parse, Gutter, Layout};
+
+const ERR_OPEN: &str = "Err:: problem opening file";
+const ERR_READ: &str = "Err:: problem reading input";
+const ERR_WIDTH: &str = "Err:: dump width must be positive number";
+const ERR_GROUP: &str = "Err:: dump group must be positive number";
+const ERR_GUTTER: &str = "Err:: unknown gutter, supports: dot, mnemonic, caret, picture";
+const ERR_PARSE: &str = "Err:: input is not valid dump";
+
+const WIDTH_FLAG: &str = "-dw:";
+const GROUP_FLAG: &str = "-dg:";
+const GUTTER_FLAG: &str = "-gt:";
+
+/// Writes dump of file given in `args`, or of standard input if none is given.
+pub fn dump(args: &[String], b: Base, o: &mut Vec<u8>) -> Result<(), &'static str> {
+    let mut l = Layout {
+        base: b,
+        ..Layout::default()
+    };
+
+    for a in args.iter() {
+        if let Some(w) = a.strip_prefix(WIDTH_FLAG) {
+            l.width = positive(w).ok_or(ERR_WIDTH)?;
+        } else if let Some(g) = a.strip_prefix(GROUP_FLAG) {
+            l.group = positive(g).ok_or(ERR_GROUP)?;
+        } else if let Some(g) = a.strip_prefix(GUTTER_FLAG) {
+            l.gutter = Gutter::ALL
+                .iter()
+                .find(|x| x.name() == g)
+                .cloned()
+                .ok_or(ERR_GUTTER)?;
+        }
+    }
+
+    let input = read(args)?;
+    o.extend_from_slice(dump_lib(&input, &l).as_bytes());
+
+    Ok(())
+}
+
+/// Writes bytes parsed from dump in file given in `args`, or in standard input if none is given.
+///
+/// Error names malformed line.
+pub fn undump(args: &[String], b: Base, o: &mut Vec<u8>) -> Result<(), String> {
+    let input = read(args)?;
+    let input = String::from_utf8(input).map_err(|e| {
+        let valid = &e.as_bytes()[..e.utf8_error().valid_up_to()];
+        parse_err(valid.iter().filter(|&&x| x == b'\n').count() + 1)
+    })?;
+
+    let bs = parse(input.as_str(), b).map_err(|e| parse_err(e.line()))?;
+    o.extend_from_slice(&bs);
+
+    Ok(())
+}
+
+fn parse_err(line: usize) -> String {
+    format!("{}, line {}", ERR_PARSE, line)
+}
+
+fn positive(s: &str) -> Option<usize> {
+    s.parse::<usize>().ok().filter(|&x| x > 0)
+}
+
+fn read(args: &[String]) -> Result<Vec<u8>, &'static str> {
+    let path = args.iter().find(|x| !x.starts_with('-'));
+    let mut input: Box<dyn Read> = match path {
+        | Some(p) => Box::new(File::open(p).map_err(|_| ERR_OPEN)?),
+        | None => Box::new(stdin().lock()),
+    };
+
+    let mut buff = Vec::new();
+    input.read_to_end(&mut buff).map_err(|_| ERR_READ)?;
+
+    Ok(buff)
+}
@@ -0,0 +1,325 @@
+use huski_lib::{acquire, relation, Code, Ranges, Scheme};
+use huski_lib::Base;
+use crate::INDENT;
+
+const ERR_NO_QUERY: &str = "\n
+        Err:: no lookup query found";
+
+/// Writes detail card for each code matching any of `queries`.
+pub fn lookup(queries: &[String], o: &mut String) -> Result<(), &'static str> {
+    if queries.is_empty() {
+        return Err(ERR_NO_QUERY);
+    }
+
+    let table = acquire(&[Ranges::Table]);
+
+    for q in queries.iter() {
+        let matches = matches(q.as_str(), table.as_slice());
+
+        if !o.is_empty() {
+            o.push('\n');
+        }
+
+        o.push('\n');
+        o.push_str(INDENT);
+        o.push_str("QUERY       | ");
+        o.push_str(q.as_str());
+        o.push('\n');
+        o.push_str(INDENT);
+        o.push_str("=================================================");
+
+        if matches.is_empty() {
+            o.push('\n');
+            o.push_str(INDENT);
+            o.push_str("no code matches");
+            continue;
+        }
+
+        for c in matches {
+            card(c, o);
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolves all `q` interpretations into codes.
+///
+/// Interpretations are tried in order: literal character,
+/// decimal number, prefixed number, caret notation, mnemonic,
+/// alias or description.
+/// Each code is listed at most once.
+fn matches<'a>(q: &str, table: &'a [Code]) -> Vec<&'a Code> {
+    let mut ixs = Vec::<usize>::with_capacity(2);
+
+    let mut chars = q.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        if c.is_ascii() {
+            ixs.push(c as usize);
+        }
+    }
+
+    if let Ok(n) = q.parse::<u8>() {
+        ixs.push(n as usize);
+    }
+
+    if let Some(n) = prefixed(q) {
+        ixs.push(n as usize);
+    }
+
+    if let Some(n) = caret(q) {
+        ixs.push(n as usize);
+    }
+
+    if let Some(c) = huski_lib::lookup(q) {
+        ixs.push(c.code() as usize);
+    }
+
+    let mut matches = Vec::with_capacity(ixs.len());
+    for ix in ixs {
+        if let Some(c) = table.get(ix) {
+            if !matches.contains(&c) {
+                matches.push(c);
+            }
+        }
+    }
+
+    matches
+}
+
+/// Parses `0x`, `0o` or `0b` prefixed number.
+fn prefixed(q: &str) -> Option<u8> {
+    if q.len() < 3 || !q.is_char_boundary(2) {
+        return None;
+    }
+
+    let radix = match &q[..2] {
+        | "0x" | "0X" => 16,
+        | "0o" | "0O" => 8,
+        | "0b" | "0B" => 2,
+        | _ => return None,
+    };
+
+    u8::from_str_radix(&q[2..], radix).ok()
+}
+
+/// Parses caret notation, i.e. `^[` for ESC or `^?` for DEL.
+fn caret(q: &str) -> Option<u8> {
+    let q = q.as_bytes();
+    if q.len() != 2 || q[0] != b'^' {
+        return None;
+    }
+
+    match q[1].to_ascii_uppercase() {
+        | b'?' => Some(127),
+        | c @ b'@'..=b'_' => Some(c - b'@'),
+        | _ => None,
+    }
+}
+
+fn card(c: &Code, o: &mut String) {
+    for b in Base::ALL.iter().cloned() {
+        let (name, numeric) = match b {
+            | Base::Binary => ("BINARY     ", format!("0b{:>07b}", c.code())),
+            | Base::Octal => ("OCTAL      ", format!("0o{:>03o}", c.code())),
+            | Base::Decimal => ("DECIMAL    ", format!("{}", c.code())),
+            | Base::Hexadecimal => ("HEXADECIMAL", format!("0x{:>02x}", c.code())),
+        };
+
+        row(name, numeric.as_str(), o);
+    }
+
+    row("HUMAN      ", c.human(), o);
+    let aliases = c.aliases().join(", ");
+    row(
+        "ALIASES    ",
+        if aliases.is_empty() { "-" } else { aliases.as_str() },
+        o,
+    );
+    row("DESCRIPTION", c.desc(), o);
+    row("CARET      ", c.caret().unwrap_or("-"), o);
+    row("C ESCAPE   ", c.c_escape(), o);
+    row("RUST ESCAPE", c.rust_escape(), o);
+    row("UNICODE    ", c.unicode(), o);
+    row("HTML       ", c.html(), o);
+    row("HTML ENTITY", c.html_entity().unwrap_or("-"), o);
+    row("PERCENT    ", c.percent(), o);
+    row("PICTURE    ", c.picture().unwrap_or("-"), o);
+    row("NAME       ", c.name(Scheme::Unicode).unwrap_or("-"), o);
+    row("ISO 6429   ", c.name(Scheme::Iso6429).unwrap_or("-"), o);
+    row("RFC 1345   ", c.name(Scheme::Rfc1345).unwrap_or("-"), o);
+    row("ADOBE GLYPH", c.name(Scheme::Adobe).unwrap_or("-"), o);
+    row("X11 KEYSYM ", c.name(Scheme::X11).unwrap_or("-"), o);
+    row("CASE       ", related(relation::case(c)), o);
+    row("PAIR       ", related(relation::pair(c)), o);
+    row("CTRL       ", related(relation::ctrl(c)), o);
+
+    if let Some(about) = c.about() {
+        paragraph("ABOUT      ", about, o);
+    }
+
+    o.push('\n');
+    o.push_str(INDENT);
+    o.push_str("-------------------------------------------------");
+}
+
+/// Writes `text` as row wrapped into lines of at most `ABOUT_WIDTH` characters.
+fn paragraph(name: &str, text: &str, o: &mut String) {
+    const ABOUT_WIDTH: usize = 60;
+
+    let mut name = name;
+    let mut line = String::with_capacity(ABOUT_WIDTH);
+    for w in text.split(' ') {
+        if !line.is_empty() && line.len() + 1 + w.len() > ABOUT_WIDTH {
+            row(name, line.as_str(), o);
+            name = "           ";
+            line.clear();
+        }
+
+        if !line.is_empty() {
+            line.push(' ');
+        }
+
+        line.push_str(w);
+    }
+
+    row(name, line.as_str(), o);
+}
+
+fn related(c: Option<&Code>) -> &'static str {
+    c.map(|x| x.human()).unwrap_or("-")
+}
+
+fn row(name: &str, value: &str, o: &mut String) {
+    o.push('\n');
+    o.push_str(INDENT);
+    o.push_str(name);
+    o.push_str(" | ");
+    o.push_str(value);
+}
+
+#[cfg(test)]
+mod tests_of_units {
+
+    use super::*;
+
+    fn codes(q: &str) -> Vec<u8> {
+        let table = acquire(&[Ranges::Table]);
+        matches(q, table.as_slice())
+            .iter()
+            .map(|x| x.code())
+            .collect()
+    }
+
+    mod lookup {
+        use super::*;
+
+        #[test]
+        fn no_query() {
+            let mut o = String::new();
+            assert_eq!(Err(ERR_NO_QUERY), lookup(&[], &mut o));
+            assert!(o.is_empty());
+        }
+
+        #[test]
+        fn queries() {
+            let mut o = String::new();
+            let qs = [String::from("^["), String::from("nothing")];
+
+            assert_eq!(Ok(()), lookup(&qs, &mut o));
+            assert!(o.contains("QUERY       | ^["));
+            assert!(o.contains("| ESC"));
+            assert!(o.contains("QUERY       | nothing"));
+            assert!(o.contains("no code matches"));
+        }
+    }
+
+    mod matches {
+        use super::*;
+
+        #[test]
+        fn character() {
+            assert_eq!(vec![b'A'], codes("A"));
+            assert_eq!(Vec::<u8>::new(), codes("é"));
+        }
+
+        #[test]
+        fn decimal() {
+            assert_eq!(vec![27], codes("27"));
+            assert_eq!(vec![b'7', 7], codes("7"));
+            assert_eq!(Vec::<u8>::new(), codes("128"));
+        }
+
+        #[test]
+        fn prefixed() {
+            assert_eq!(vec![27], codes("0x1b"));
+            assert_eq!(vec![27], codes("0o33"));
+            assert_eq!(vec![27], codes("0b11011"));
+            assert_eq!(Vec::<u8>::new(), codes("0x80"));
+        }
+
+        #[test]
+        fn caret() {
+            assert_eq!(vec![27], codes("^["));
+            assert_eq!(vec![127], codes("^?"));
+        }
+
+        #[test]
+        fn mnemonic() {
+            assert_eq!(vec![27], codes("ESC"));
+            assert_eq!(vec![27], codes("esc"));
+        }
+
+        #[test]
+        fn description() {
+            assert_eq!(vec![27], codes("Escape"));
+        }
+
+        #[test]
+        fn alias() {
+            assert_eq!(vec![17], codes("XON"));
+        }
+
+        #[test]
+        fn distinct() {
+            assert_eq!(vec![b'0', 0], codes("0"));
+        }
+
+        #[test]
+        fn none() {
+            assert_eq!(Vec::<u8>::new(), codes("nothing"));
+            assert_eq!(Vec::<u8>::new(), codes(""));
+        }
+    }
+
+    mod prefixed {
+        use super::super::prefixed;
+
+        #[test]
+        fn basic_test() {
+            assert_eq!(Some(255), prefixed("0xFF"));
+            assert_eq!(Some(8), prefixed("0O10"));
+            assert_eq!(Some(1), prefixed("0B1"));
+            assert_eq!(None, prefixed("0x"));
+            assert_eq!(None, prefixed("0x100"));
+            assert_eq!(None, prefixed("0z1"));
+            assert_eq!(None, prefixed("0é"));
+        }
+    }
+
+    mod caret {
+        use super::super::caret;
+
+        #[test]
+        fn basic_test() {
+            assert_eq!(Some(0), caret("^@"));
+            assert_eq!(Some(1), caret("^a"));
+            assert_eq!(Some(31), caret("^_"));
+            assert_eq!(Some(127), caret("^?"));
+            assert_eq!(None, caret("^"));
+            assert_eq!(None, caret("^ab"));
+            assert_eq!(None, caret("x["));
+            assert_eq!(None, caret("^1"));
+        }
+    }
+}
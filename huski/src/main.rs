@@ -1,5 +1,23 @@
-use std::io::{stdout, Write};
-use huski_lib::{acquire, acquire_apart, Ranges, Code};
+use std::io::{stdin, stdout, Write};
+use std::process::exit;
+use huski_lib::{acquire, acquire_apart, Base, Ranges, Code, Scheme};
+use huski_lib::order::{class, group, Grouping, CLASSES};
+
+mod annotate;
+mod diff;
+mod dump;
+mod lookup;
+mod show;
+mod sort;
+mod stats;
+
+use annotate::annotate;
+use diff::diff;
+use dump::{dump, undump};
+use lookup::lookup;
+use show::show;
+use sort::sort;
+use stats::stats;
 
 const INTRO: &str = "\n\n
         @***************************************************************************************************@
@@ -7,19 +25,58 @@ const INTRO: &str = "\n\n
         @~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~@";
 
 const HELP: &str = "
-        SUBSETS
+        LOOKUP
         ----------------------------
-        -p       | printable
-        -c       | control
-        -lc      | capital letters
-        -ls      | small letters
-        -l       | all letters
-        -d       | digits
-        -s       | symbols
-        
-        SET
+        lookup q | detail card for each code matching query q, more queries can follow
+                 | q can be character, decimal, 0x/0o/0b prefixed number, caret notation (^[), mnemonic (ESC)
+                 | alias (ALT) or description (Escape)
+                 | card of control code explains its historic purpose and modern usage
+                 | lookup must be first parameter
+
+        ANNOTATE
+        ----------------------------
+        annotate | row for each byte of file, or of standard input when no file is given: offset, line:column,
+                 | numeric value in -nt:base, human representation and class, annotate must be first parameter
+
+        DIFF
+        ----------------------------
+        diff A B | row for each line of files A and B that differs: line:column in A and B and offsets of
+                 | first differing byte, differing bytes of A and B with control codes, space and bytes above
+                 | 127 in angle brackets, lines are aligned by visible content, added or removed line is
+                 | single row, diff must be first parameter
+                 | exit status is 0 for no differences, 1 for differences, 2 for trouble
+
+        STATS
+        ----------------------------
+        stats    | statistics of file, or of standard input when no file is given: count of bytes,
+                 | non-ASCII bytes and lines, mix of line endings, longest lines as line:length
+                 | and bar charts of classes, control categories and codes, stats must be first parameter
+
+        DUMP
+        ----------------------------
+        dump     | xxd-like dump of file, or of standard input when no file is given, prints no intro
+        undump   | bytes of dump, or of xxd output, in file, or in standard input when no file is given
+                 | dump or undump must be first parameter, -nt:base applies to both, defaults to nt:16
+        -dw:n    | dump width, bytes per line, defaults to dw:16
+        -dg:n    | dump group, bytes per group, defaults to dg:2
+        -gt:name | gutter, defaults to gt:mnemonic, supports: dot — control codes as dots,
+                 | mnemonic — control codes as <ESC>, caret — control codes as ^[,
+                 | picture — control codes as Unicode control pictures
+
+        SHOW-INVISIBLES
+        ----------------------------
+        show-invisibles | file, or standard input when no file is given, with control codes visible,
+                        | prints no intro, show-invisibles must be first parameter, line feed is kept,
+                        | bytes above 127 are shown in meta notation, i.e. M-a for 0xe1
+        -st:name        | style, defaults to st:caret, supports: caret — ^M, mnemonic — <CR>,
+                        | escape — \\r, picture — Unicode control pictures
+
+        SORT
         ----------------------------
-        -t       | whole table
+        sort     | sorts lines of standard input, prints no intro, sort must be first parameter
+        -co:type | collation, defaults to co:code, supports: code — code value, nocase — case-insensitive,
+                 | dictionary — only letters, digits and blanks, case-insensitive,
+                 | natural — digit runs by numeric value
 
         GENERAL
         ----------------------------
@@ -27,6 +84,14 @@ const HELP: &str = "
         -nt:base | number type, defaults to nt:10 = decimal, supports: binary, octal, decimal, hexadecimal
         -tt:type | table type, defaults to tt:c = classic order, supports: s — special, c — classic 
                  | if -tt:s prints subset ordered-table in order: lc,ls,d,s,c, works only with -t
+        -ec:list | extra columns, comma separated list of: caret, c, rust, unicode, html, entity, percent, picture
+                 | caret — caret notation, c — C escape, rust — Rust escape, unicode — U+ notation,
+                 | html — numeric reference, entity — named reference, percent — URL encoding,
+                 | picture — Unicode control picture, also naming schemes: name, iso, rfc, adobe, x11
+                 | name — Unicode name, iso — ISO 6429 control name, rfc — RFC 1345 mnemonic,
+                 | adobe — Adobe Glyph List name, x11 — X11 keysym, works only with subsets
+        -gc      | groups control codes by functional category: tc, fe, dc, is, ce, others last,
+                 | other codes precede in plain subset layout, works only with subsets
         
         No parameter is same as --help. First known non-optional parameter is considered function match.
         Similarly, first valid optional parameter is considered match.
@@ -51,46 +116,204 @@ const VERSION: &str = "
 const ERR_NO_PARAM: &str = "\n
         Err:: no valid function parameter found";
 
+const ERR_GROUP_TABLE: &str = "\n
+        Err:: -gc works only with subsets, not with table";
+
 const INDENT: &str = "        ";
 
-#[derive(Clone)]
-enum Base {
-    Binary = 2,
-    Octal = 8,
-    Decimal = 10,
-    Hexadecimal = 16,
+/// Extra subset column.
+struct Column {
+    name: &'static str,
+    header: &'static str,
+    width: usize,
+    value: fn(&Code) -> Option<&'static str>,
 }
 
-static BASE_VARIANTS: [Base; 4] = [Base::Binary, Base::Octal, Base::Decimal, Base::Hexadecimal];
-
-static RANGES_MAP: [(&str, Ranges); 8] = [
-    (PRINTABLE_SUBSET_NAME, Ranges::Printable),
-    ("c", Ranges::Control),
-    ("lc", Ranges::Capital),
-    ("ls", Ranges::Small),
-    (LETTERS_SUBSET_NAME, Ranges::Letters),
-    ("d", Ranges::Digits),
-    ("s", Ranges::Symbols),
-    (TABLE_SET_NAME, Ranges::Table),
+static COLUMNS: [Column; 13] = [
+    Column {
+        name: "caret",
+        header: "CARET",
+        width: 7,
+        value: |c| c.caret(),
+    },
+    Column {
+        name: "c",
+        header: "C ESC",
+        width: 7,
+        value: |c| Some(c.c_escape()),
+    },
+    Column {
+        name: "rust",
+        header: "RUST ESC",
+        width: 10,
+        value: |c| Some(c.rust_escape()),
+    },
+    Column {
+        name: "unicode",
+        header: "UNICODE",
+        width: 9,
+        value: |c| Some(c.unicode()),
+    },
+    Column {
+        name: "html",
+        header: "HTML",
+        width: 8,
+        value: |c| Some(c.html()),
+    },
+    Column {
+        name: "entity",
+        header: "ENTITY",
+        width: 11,
+        value: |c| c.html_entity(),
+    },
+    Column {
+        name: "percent",
+        header: "PERCENT",
+        width: 9,
+        value: |c| Some(c.percent()),
+    },
+    Column {
+        name: "picture",
+        header: "PICTURE",
+        width: 9,
+        value: |c| c.picture(),
+    },
+    Column {
+        name: "name",
+        header: "UNICODE NAME",
+        width: 24,
+        value: |c| c.name(Scheme::Unicode),
+    },
+    Column {
+        name: "iso",
+        header: "ISO 6429",
+        width: 29,
+        value: |c| c.name(Scheme::Iso6429),
+    },
+    Column {
+        name: "rfc",
+        header: "RFC 1345",
+        width: 10,
+        value: |c| c.name(Scheme::Rfc1345),
+    },
+    Column {
+        name: "adobe",
+        header: "ADOBE",
+        width: 14,
+        value: |c| c.name(Scheme::Adobe),
+    },
+    Column {
+        name: "x11",
+        header: "X11",
+        width: 14,
+        value: |c| c.name(Scheme::X11),
+    },
 ];
 
-const PRINTABLE_SUBSET_NAME: &str = "p";
-const LETTERS_SUBSET_NAME: &str = "l";
-const TABLE_SET_NAME: &str = "t";
+const LOOKUP_FUNCTION: &str = "lookup";
+const SORT_FUNCTION: &str = "sort";
+const ANNOTATE_FUNCTION: &str = "annotate";
+const DIFF_FUNCTION: &str = "diff";
+const STATS_FUNCTION: &str = "stats";
+const DUMP_FUNCTION: &str = "dump";
+const UNDUMP_FUNCTION: &str = "undump";
+const SHOW_FUNCTION: &str = "show-invisibles";
 
 fn main() {
-    write(INTRO);
-
     let args = std::env::args();
     let args = args.collect::<Vec<String>>();
 
+    // data filters write no intro
+    if let Some(f) = args.get(1) {
+        let params = &args[2..];
+        let mut output = Vec::new();
+        let res = match f.as_str() {
+            | SORT_FUNCTION => {
+                Some(sort(params, &mut stdin().lock(), &mut output).map_err(String::from))
+            },
+            | DUMP_FUNCTION => Some(
+                dump(params, base(params, Base::Hexadecimal), &mut output).map_err(String::from),
+            ),
+            | UNDUMP_FUNCTION => Some(undump(params, base(params, Base::Hexadecimal), &mut output)),
+            // streams straight to standard output, `output` stays empty
+            | SHOW_FUNCTION => Some(show(params, &mut stdout().lock()).map_err(String::from)),
+            | _ => None,
+        };
+
+        if let Some(res) = res {
+            match res {
+                | Ok(_) => write_raw(output.as_slice()),
+                | Err(e) => {
+                    eprintln!("{}", e);
+                    exit(1);
+                },
+            }
+
+            return;
+        }
+    }
+
+    write(INTRO);
+
     if args.len() == 1 {
-        write(HELP);
+        write(help().as_str());
+        return;
+    }
+
+    if args[1] == LOOKUP_FUNCTION {
+        let mut output = String::with_capacity(1000);
+        match lookup(&args[2..], &mut output) {
+            | Ok(_) => write(output.as_str()),
+            | Err(e) => write(e),
+        }
+
+        return;
+    }
+
+    if args[1] == DIFF_FUNCTION {
+        let mut output = String::with_capacity(1000);
+        // exit code follows diff(1), 1 for differences, 2 for trouble
+        match diff(&args[2..], &mut output) {
+            | Ok(count) => {
+                write(output.as_str());
+                if count > 0 {
+                    exit(1);
+                }
+            },
+            | Err(e) => {
+                eprintln!("{}", e);
+                exit(2);
+            },
+        }
+
+        return;
+    }
+
+    if args[1] == STATS_FUNCTION {
+        let mut output = String::with_capacity(10000);
+        match stats(&args[2..], &mut output) {
+            | Ok(_) => write(output.as_str()),
+            | Err(e) => {
+                eprintln!("{}", e);
+                exit(1);
+            },
+        }
+
+        return;
+    }
+
+    if args[1] == ANNOTATE_FUNCTION {
+        // streams straight to standard output
+        if let Err(e) = annotate(&args[2..], base(&args, Base::Decimal), &mut stdout().lock()) {
+            eprintln!("{}", e);
+            exit(1);
+        }
+
         return;
     }
 
     use std::collections::HashMap;
-    let ranges_map = RANGES_MAP.iter().cloned();
+    let ranges_map = Ranges::ALL.iter().map(|x| (x.short_flag(), x.clone()));
     let ranges_map: HashMap<&str, Ranges> = HashMap::from_iter(ranges_map);
 
     let mut table_output = false;
@@ -106,7 +329,7 @@ fn main() {
                 return;
             },
             | "--help" => {
-                write(HELP);
+                write(help().as_str());
                 return;
             },
             | pmtr => {
@@ -119,22 +342,15 @@ fn main() {
                     continue;
                 }
 
-                if name == TABLE_SET_NAME {
+                if name == Ranges::Table.short_flag() {
                     table_output = true;
 
                     for a2 in args.iter() {
                         const TABLE_TYPE_FLAG: &str = "-tt:";
-                        if a2.starts_with(TABLE_TYPE_FLAG) {
-                            let tt = &a2[TABLE_TYPE_FLAG.len()..];
-
+                        if let Some(tt) = a2.strip_prefix(TABLE_TYPE_FLAG) {
                             match tt {
                                 | "s" => {
-                                    ranges.push(Ranges::Capital);
-                                    ranges.push(Ranges::Small);
-                                    ranges.push(Ranges::Digits);
-                                    ranges.push(Ranges::Symbols);
-                                    ranges.push(Ranges::Control);
-
+                                    ranges.extend_from_slice(&CLASSES);
                                     break 'param;
                                 },
                                 | "c" => break,
@@ -151,23 +367,44 @@ fn main() {
         }
     }
 
-    if ranges.len() == 0 {
+    if ranges.is_empty() {
         write(ERR_NO_PARAM);
         return;
     }
 
     let mut base = Base::Decimal;
-    for a in args {
+    for a in args.iter() {
         if let Some(b) = aq_base(a.as_str()) {
             base = b;
             break;
         }
     }
 
+    let mut columns = Vec::new();
+    for a in args.iter() {
+        const EXTRA_COLUMNS_FLAG: &str = "-ec:";
+        if let Some(names) = a.strip_prefix(EXTRA_COLUMNS_FLAG) {
+            for n in names.split(',') {
+                if let Some(col) = COLUMNS.iter().find(|x| x.name == n) {
+                    columns.push(col);
+                }
+            }
+
+            break;
+        }
+    }
+
+    let grouped = args.iter().any(|x| x == "-gc");
+
     let ranges = ranges.as_slice();
     let mut output = String::with_capacity(3000);
 
     if table_output {
+        if grouped {
+            write(ERR_GROUP_TABLE);
+            return;
+        }
+
         let apart = acquire_apart(ranges);
 
         let special = apart.len() > 1;
@@ -182,18 +419,81 @@ fn main() {
 
             codes
         } else {
-            apart[0].iter().map(|x| Some(x)).collect()
+            apart[0].iter().map(Some).collect()
         };
 
         set(codes.as_slice(), &mut output, base, special);
     } else {
         let codes = acquire(ranges);
-        subset(codes.as_slice(), &mut output, base);
+        if grouped {
+            // only control codes are grouped, others keep subset layout
+            let (controls, others): (Vec<_>, Vec<_>) =
+                codes.into_iter().partition(|x| class(x) == Ranges::Control);
+
+            if !others.is_empty() {
+                subset(
+                    others.as_slice(),
+                    &mut output,
+                    base.clone(),
+                    columns.as_slice(),
+                );
+            }
+
+            let groups = group(&controls, Grouping::Classes(&Ranges::CATEGORIES));
+            for (ix, g) in groups.iter().enumerate() {
+                if g.is_empty() {
+                    continue;
+                }
+
+                let title = Ranges::CATEGORIES
+                    .get(ix)
+                    .map(|x| x.description())
+                    .unwrap_or("other control codes");
+
+                if !output.is_empty() {
+                    output.push('\n');
+                }
+
+                output.push('\n');
+                output.push_str(INDENT);
+                output.push_str(title.to_uppercase().as_str());
+                subset(g.as_slice(), &mut output, base.clone(), columns.as_slice());
+            }
+        } else {
+            subset(codes.as_slice(), &mut output, base, columns.as_slice());
+        }
     };
 
     write(output.as_str());
 }
 
+fn help() -> String {
+    let mut subsets = String::with_capacity(1000);
+    let mut set = String::with_capacity(100);
+
+    for r in Ranges::ALL.iter() {
+        let o = if let Ranges::Table = r { &mut set } else { &mut subsets };
+
+        o.push('\n');
+        o.push_str(INDENT);
+        let flag = format!("-{:<8}", r.short_flag());
+        o.push_str(flag.as_str());
+        o.push_str("| ");
+        o.push_str(r.description());
+    }
+
+    format!(
+        "
+        SUBSETS
+        ----------------------------{}
+        
+        SET
+        ----------------------------{}
+{}",
+        subsets, set, HELP
+    )
+}
+
 fn set(codes: &[Option<&Code>], o: &mut String, b: Base, special: bool) {
     let f = match b {
         | Base::Binary => b_set,
@@ -273,7 +573,7 @@ fn set(codes: &[Option<&Code>], o: &mut String, b: Base, special: bool) {
     }
 }
 
-fn subset(codes: &[Code], o: &mut String, b: Base) {
+fn subset(codes: &[Code], o: &mut String, b: Base, columns: &[&Column]) {
     let f = match b {
         | Base::Binary => b_subset,
         | Base::Octal => o_subset,
@@ -281,13 +581,20 @@ fn subset(codes: &[Code], o: &mut String, b: Base) {
         | Base::Hexadecimal => h_subset,
     };
 
-    const HEADER: &str = "  NUMERIC  | HUMAN | DESCRIPTION";
     o.push('\n');
     o.push_str(INDENT);
-    o.push_str(HEADER);
+    o.push_str("  NUMERIC  | HUMAN |");
+    for col in columns.iter() {
+        let header = format!("{:^w$}|", col.header, w = col.width);
+        o.push_str(header.as_str());
+    }
+    o.push_str(" DESCRIPTION");
     o.push('\n');
     o.push_str(INDENT);
     o.push_str("-------------------------------------------------");
+    for col in columns.iter() {
+        o.push_str("-".repeat(col.width + 1).as_str());
+    }
 
     for c in codes.iter() {
         o.push('\n');
@@ -297,7 +604,13 @@ fn subset(codes: &[Code], o: &mut String, b: Base) {
         o.push_str(numeric.as_str());
         let human = format!("{:^7}", c.human());
         o.push_str(human.as_str());
-        o.push_str("| ");
+        o.push('|');
+        for col in columns.iter() {
+            let value = (col.value)(c).unwrap_or("-");
+            let value = format!("{:^w$}|", value, w = col.width);
+            o.push_str(value.as_str());
+        }
+        o.push(' ');
         o.push_str(c.desc());
     }
 
@@ -322,20 +635,34 @@ fn subset(codes: &[Code], o: &mut String, b: Base) {
 
 fn write(s: &str) {
     let mut so = stdout();
-    if let Ok(_) = write!(so, "{}\n\n", s) {
-        if let Ok(_) = so.flush() {
-            return;
-        }
+    if write!(so, "{}\n\n", s).is_ok() && so.flush().is_ok() {
+        return;
     }
 
     panic!("Problem writing output");
 }
 
+fn write_raw(bs: &[u8]) {
+    let mut so = stdout();
+    if so.write_all(bs).is_ok() && so.flush().is_ok() {
+        return;
+    }
+
+    panic!("Problem writing output");
+}
+
+/// First valid `-nt:` parameter, `default` if there is none.
+fn base(args: &[String], default: Base) -> Base {
+    args.iter()
+        .find_map(|x| aq_base(x.as_str()))
+        .unwrap_or(default)
+}
+
 fn aq_base(s: &str) -> Option<Base> {
     const NUMBER_TYPE_FLAG: &str = "-nt:";
-    if s.starts_with(NUMBER_TYPE_FLAG) {
-        if let Ok(b) = s[NUMBER_TYPE_FLAG.len()..].parse::<u8>() {
-            for v in BASE_VARIANTS.iter().cloned() {
+    if let Some(b) = s.strip_prefix(NUMBER_TYPE_FLAG) {
+        if let Ok(b) = b.parse::<u8>() {
+            for v in Base::ALL.iter().cloned() {
                 if v.clone() as u8 == b {
                     return Some(v);
                 }
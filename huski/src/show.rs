@@ -0,0 +1,55 @@
+use std::fs::File;
+use std::io::{stdin, BufRead, BufReader, Read, Write};
+use huski_lib::visible::{Style, VisibleWriter};
+
+const ERR_OPEN: &str = "Err:: problem opening file";
+const ERR_READ: &str = "Err:: problem reading input";
+const ERR_WRITE: &str = "Err:: problem writing output";
+const ERR_STYLE: &str = "Err:: unknown style, supports: caret, mnemonic, escape, picture";
+
+const STYLE_FLAG: &str = "-st:";
+
+/// Writes file given in `args`, or standard input if none is given, with
+/// invisible codes rendered by style given in `args`, `caret` by default.
+///
+/// Output is flushed after each line, thus it suits endless input too.
+pub fn show(args: &[String], o: &mut impl Write) -> Result<(), &'static str> {
+    let mut style = Style::Caret;
+    for a in args.iter() {
+        if let Some(s) = a.strip_prefix(STYLE_FLAG) {
+            style = Style::ALL
+                .iter()
+                .find(|x| x.name() == s)
+                .cloned()
+                .ok_or(ERR_STYLE)?;
+        }
+    }
+
+    let path = args.iter().find(|x| !x.starts_with('-'));
+    let input: Box<dyn Read> = match path {
+        | Some(p) => Box::new(File::open(p).map_err(|_| ERR_OPEN)?),
+        | None => Box::new(stdin().lock()),
+    };
+
+    let mut input = BufReader::new(input);
+    let mut w = VisibleWriter::new(o, style);
+
+    loop {
+        let buff = input.fill_buf().map_err(|_| ERR_READ)?;
+        if buff.is_empty() {
+            break;
+        }
+
+        for line in buff.split_inclusive(|&x| x == b'\n') {
+            w.write_all(line).map_err(|_| ERR_WRITE)?;
+            if line.ends_with(b"\n") {
+                w.flush().map_err(|_| ERR_WRITE)?;
+            }
+        }
+
+        let len = buff.len();
+        input.consume(len);
+    }
+
+    w.flush().map_err(|_| ERR_WRITE)
+}
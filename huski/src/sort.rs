@@ -0,0 +1,41 @@
+use std::io::Read;
+use huski_lib::collate::{compare, Collation};
+
+const ERR_COLLATION: &str = "Err:: unknown collation, supports: code, nocase, dictionary, natural";
+const ERR_READ: &str = "Err:: problem reading input";
+
+const COLLATION_FLAG: &str = "-co:";
+
+/// Writes lines of `input` sorted by collation given in `args`, `code` by default.
+///
+/// Sort is stable, line feed terminates each output line.
+pub fn sort(args: &[String], input: &mut impl Read, o: &mut Vec<u8>) -> Result<(), &'static str> {
+    let mut collation = Collation::Code;
+    for a in args.iter() {
+        if let Some(c) = a.strip_prefix(COLLATION_FLAG) {
+            collation = c.parse().map_err(|_| ERR_COLLATION)?;
+            break;
+        }
+    }
+
+    let mut buff = Vec::new();
+    if input.read_to_end(&mut buff).is_err() {
+        return Err(ERR_READ);
+    }
+
+    if buff.is_empty() {
+        return Ok(());
+    }
+
+    let buff = buff.strip_suffix(b"\n").unwrap_or(&buff);
+    let mut lines = buff.split(|&x| x == b'\n').collect::<Vec<&[u8]>>();
+    lines.sort_by(|a, b| compare(a, b, collation));
+
+    o.reserve(buff.len() + 1);
+    for l in lines {
+        o.extend_from_slice(l);
+        o.push(b'\n');
+    }
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use std::fs::File;
+use std::io::{stdin, Read};
+use huski_lib::stats::{stats_read, Stats};
+use huski_lib::order::CLASSES;
+use huski_lib::{Ranges, CODES};
+use crate::INDENT;
+
+const ERR_OPEN: &str = "Err:: problem opening file";
+const ERR_READ: &str = "Err:: problem reading input";
+
+const BAR_WIDTH: usize = 40;
+
+/// Writes statistics of file given in `args`, or of standard input if none is given.
+pub fn stats(args: &[String], o: &mut String) -> Result<(), &'static str> {
+    let path = args.iter().find(|x| !x.starts_with('-'));
+    let input: Box<dyn Read> = match path {
+        | Some(p) => Box::new(File::open(p).map_err(|_| ERR_OPEN)?),
+        | None => Box::new(stdin().lock()),
+    };
+
+    let s = stats_read(input).map_err(|_| ERR_READ)?;
+
+    summary(&s, o);
+
+    let classes = CLASSES
+        .iter()
+        .map(|x| (x.description(), s.count(x.clone())))
+        .chain([("non-ASCII", s.non_ascii())])
+        .collect::<Vec<(&str, usize)>>();
+    histogram("CLASS", &classes, o);
+
+    let categories = Ranges::CATEGORIES
+        .iter()
+        .map(|x| (x.description(), s.count(x.clone())))
+        .collect::<Vec<(&str, usize)>>();
+    histogram("CONTROL CATEGORY", &categories, o);
+
+    let codes = CODES
+        .iter()
+        .map(|x| match x.code() {
+            | b' ' => (x.aliases()[0], s.code(b' ')),
+            | c => (x.human(), s.code(c)),
+        })
+        .filter(|x| x.1 > 0)
+        .collect::<Vec<(&str, usize)>>();
+    histogram("CODE", &codes, o);
+
+    Ok(())
+}
+
+fn summary(s: &Stats, o: &mut String) {
+    let e = s.endings();
+    let longest = match s.longest() {
+        | [] => String::from("-"),
+        | l => l
+            .iter()
+            .map(|x| format!("{}:{}", x.0, x.1))
+            .collect::<Vec<String>>()
+            .join(" | "),
+    };
+
+    let rows = [
+        ("BYTES        ", s.total().to_string()),
+        ("NON-ASCII    ", s.non_ascii().to_string()),
+        ("LINES        ", s.lines().to_string()),
+        (
+            "LINE ENDINGS ",
+            format!("LF {} | CRLF {} | CR {}", e.lf, e.crlf, e.cr),
+        ),
+        ("LONGEST LINES", longest),
+    ];
+
+    o.push('\n');
+    for (name, value) in rows {
+        o.push('\n');
+        o.push_str(INDENT);
+        o.push_str(name);
+        o.push_str(" | ");
+        o.push_str(value.as_str());
+    }
+}
+
+/// Writes bar chart of `rows`, bars are scaled to greatest count.
+fn histogram(title: &str, rows: &[(&str, usize)], o: &mut String) {
+    let width = rows.iter().map(|x| x.0.chars().count()).max().unwrap_or(0);
+    let max = rows.iter().map(|x| x.1).max().unwrap_or(0);
+
+    o.push_str("\n\n");
+    o.push_str(INDENT);
+    o.push_str(title);
+    o.push('\n');
+    o.push_str(INDENT);
+    o.push_str("----------------------------");
+
+    for &(name, count) in rows {
+        let bar = match count {
+            | 0 => 0,
+            | c => (c * BAR_WIDTH / max).max(1),
+        };
+
+        let row = format!(
+            "{:<w$} | {:>9} | {}",
+            name,
+            count,
+            "#".repeat(bar),
+            w = width
+        );
+
+        o.push('\n');
+        o.push_str(INDENT);
+        o.push_str(row.trim_end());
+    }
+}